@@ -1,9 +1,13 @@
 use crate::configuration::load_config_files;
-use crate::state::context::write_context;
-use crate::state::keybinds::{register_open_search_keybind, register_show_popup_keybind};
+use crate::state::context::{save_notes_if_changed, write_context};
+use crate::state::keybinds::{
+    register_open_search_keybind, register_show_popup_keybind, register_toggle_bookmarks_keybind,
+    register_toggle_watchlist_keybind,
+};
 use crate::threads::{init_threads, unload_threads};
 use log::info;
 use nexus::gui::{register_render, RenderType};
+use std::time::Duration;
 
 pub const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const PACKAGE_NAME: &str = env!("CARGO_PKG_NAME");
@@ -15,6 +19,8 @@ pub fn load() {
     register_renderers();
     register_show_popup_keybind();
     register_open_search_keybind();
+    register_toggle_watchlist_keybind();
+    register_toggle_bookmarks_keybind();
     info!("[load] {PACKAGE_NAME} loaded");
 }
 
@@ -35,5 +41,6 @@ fn register_renderers() {
 pub fn unload() {
     info!("[unload] Unloading {PACKAGE_NAME} v{PACKAGE_VERSION}");
     unload_threads();
+    save_notes_if_changed(Duration::ZERO);
     info!("[unload] {PACKAGE_NAME} unloaded");
 }
@@ -1,8 +1,10 @@
 use log::{error, warn};
 
+#[derive(Debug)]
 pub enum ApiError {
     Internal(String),
     Unexpected(String),
+    Transient(String),
 }
 
 impl ApiError {
@@ -10,6 +12,24 @@ impl ApiError {
         match self {
             ApiError::Internal(e) => error!("Internal error: {e}"),
             ApiError::Unexpected(e) => warn!("Unexpected error: {e}"),
+            ApiError::Transient(e) => warn!("Transient error: {e}"),
         }
     }
+
+    // Rate limiting, server errors and connection problems are worth retrying.
+    pub fn from_request_error(error: ureq::Error, message: &str) -> Self {
+        match error {
+            ureq::Error::Status(code, _) if code == 429 || code >= 500 => {
+                ApiError::Transient(format!("{message}: status {code}"))
+            }
+            ureq::Error::Transport(transport) => {
+                ApiError::Transient(format!("{message}: {transport}"))
+            }
+            error => ApiError::Unexpected(format!("{message}: {error}")),
+        }
+    }
+
+    pub fn is_transient(&self) -> bool {
+        matches!(self, ApiError::Transient(_))
+    }
 }
@@ -0,0 +1,28 @@
+use crate::service::http_client::get_sync;
+
+use crate::api::api_error::ApiError;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+const DATAWARS2_URL: &str = "https://api.datawars2.ie/gw2/v2";
+
+#[derive(Deserialize, Debug)]
+pub struct PriceHistoryApiResponse {
+    pub date: DateTime<Utc>,
+    pub buy_price_max: Option<u32>,
+    pub sell_price_min: Option<u32>,
+}
+
+fn price_history_path(item_id: u32) -> String {
+    format!("{}/history/json?itemID={}", DATAWARS2_URL, item_id)
+}
+
+pub fn get_price_history(item_id: u32) -> Result<Vec<PriceHistoryApiResponse>, ApiError> {
+    get_sync(price_history_path(item_id))
+        .map_err(|e| ApiError::Unexpected(format!("Could not fetch price history: {e}")))
+        .and_then(|response| {
+            response
+                .into_json()
+                .map_err(|e| ApiError::Internal(format!("Failed to parse price history json: {e}")))
+        })
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct ProfessionApiResponse {
+    pub name: String,
+    pub code: u8,
+    pub icon_big: String,
+    pub skills_by_palette: Vec<(u16, u32)>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SpecializationApiResponse {
+    pub id: u32,
+    pub name: String,
+    pub icon: String,
+    pub major_traits: Vec<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LegendApiResponse {
+    pub code: u8,
+    pub swap: u32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NamedApiResponse {
+    pub id: u32,
+    pub name: String,
+    pub icon: Option<String>,
+}
@@ -0,0 +1,68 @@
+use crate::service::http_client::get_sync;
+
+use crate::api::api_error::ApiError;
+use crate::api::gw2::GW2_API_URL;
+use build_api_response::{
+    LegendApiResponse, NamedApiResponse, ProfessionApiResponse, SpecializationApiResponse,
+};
+use log::debug;
+use serde::de::DeserializeOwned;
+
+pub mod build_api_response;
+
+// Older schemas lack profession codes, palette mappings and legend codes.
+const SCHEMA_VERSION: &str = "2019-12-19T00:00:00.000Z";
+
+pub fn get_professions() -> Result<Vec<ProfessionApiResponse>, ApiError> {
+    debug!("[get_professions] started");
+    get_json(
+        format!("{}/professions?ids=all&v={}", GW2_API_URL, SCHEMA_VERSION),
+        "professions",
+    )
+}
+
+pub fn get_legends() -> Result<Vec<LegendApiResponse>, ApiError> {
+    debug!("[get_legends] started");
+    get_json(
+        format!("{}/legends?ids=all&v={}", GW2_API_URL, SCHEMA_VERSION),
+        "legends",
+    )
+}
+
+pub fn get_specializations(ids: &[u32]) -> Result<Vec<SpecializationApiResponse>, ApiError> {
+    get_json(ids_path("specializations", ids), "specializations")
+}
+
+pub fn get_traits(ids: &[u32]) -> Result<Vec<NamedApiResponse>, ApiError> {
+    get_json(ids_path("traits", ids), "traits")
+}
+
+pub fn get_skills(ids: &[u32]) -> Result<Vec<NamedApiResponse>, ApiError> {
+    get_json(ids_path("skills", ids), "skills")
+}
+
+pub fn get_pets(ids: &[u32]) -> Result<Vec<NamedApiResponse>, ApiError> {
+    get_json(ids_path("pets", ids), "pets")
+}
+
+fn get_json<T: DeserializeOwned>(path: String, description: &str) -> Result<T, ApiError> {
+    get_sync(path)
+        .map_err(|e| ApiError::Unexpected(format!("Failed to fetch {description}: {e}")))
+        .and_then(|response| {
+            response
+                .into_json::<T>()
+                .map_err(|e| ApiError::Internal(format!("Failed to parse {description} json: {e}")))
+        })
+}
+
+fn ids_path(endpoint: &str, ids: &[u32]) -> String {
+    format!(
+        "{}/{}?ids={}",
+        GW2_API_URL,
+        endpoint,
+        ids.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct ItemApiResponse {
+    pub id: u32,
+    pub rarity: String,
+    #[serde(default)]
+    pub flags: Vec<String>,
+}
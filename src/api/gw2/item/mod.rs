@@ -0,0 +1,31 @@
+use crate::service::http_client::get_sync;
+
+use crate::api::api_error::ApiError;
+use crate::api::gw2::GW2_API_URL;
+use item_api_response::ItemApiResponse;
+use log::debug;
+
+pub mod item_api_response;
+
+pub fn get_items(item_ids: &[u32]) -> Result<Vec<ItemApiResponse>, ApiError> {
+    debug!("[get_items] started for {} items", item_ids.len());
+
+    match get_sync(items_path(item_ids)) {
+        Ok(response) => response
+            .into_json::<Vec<ItemApiResponse>>()
+            .map_err(|e| ApiError::Internal(format!("Failed to parse items json: {}", e))),
+        Err(ureq::Error::Status(404, _)) => Ok(vec![]),
+        Err(e) => Err(ApiError::from_request_error(e, "Failed to fetch items")),
+    }
+}
+
+fn items_path(ids: &[u32]) -> String {
+    format!(
+        "{}/items?ids={}",
+        GW2_API_URL,
+        ids.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
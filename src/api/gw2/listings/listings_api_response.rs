@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct ListingApiResponse {
+    pub unit_price: u32,
+    pub quantity: u32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ListingsApiResponse {
+    pub buys: Vec<ListingApiResponse>,
+    pub sells: Vec<ListingApiResponse>,
+}
@@ -0,0 +1,24 @@
+use crate::service::http_client::get_sync;
+
+use crate::api::api_error::ApiError;
+use crate::api::gw2::GW2_API_URL;
+use listings_api_response::ListingsApiResponse;
+use log::debug;
+
+pub mod listings_api_response;
+
+pub fn get_listings(item_id: u32) -> Result<ListingsApiResponse, ApiError> {
+    debug!("[get_listings] started for {item_id}");
+
+    get_sync(listings_path(item_id))
+        .map_err(|e| ApiError::Unexpected(format!("Failed to fetch listings: {}", e)))
+        .and_then(|response| {
+            response
+                .into_json::<ListingsApiResponse>()
+                .map_err(|e| ApiError::Internal(format!("Failed to parse listings json: {}", e)))
+        })
+}
+
+fn listings_path(item_id: u32) -> String {
+    format!("{}/commerce/listings/{}", GW2_API_URL, item_id)
+}
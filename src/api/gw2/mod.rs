@@ -1,3 +1,6 @@
+pub mod build;
+pub mod item;
+pub mod listings;
 pub mod price;
 
 const GW2_API_URL: &str = "https://api.guildwars2.com/v2";
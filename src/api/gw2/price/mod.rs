@@ -5,20 +5,23 @@ use crate::api::gw2::GW2_API_URL;
 use log::debug;
 use price_api_response::PriceApiResponse;
 
-mod buy_price;
+pub mod buy_price;
 pub mod price_api_response;
-mod sell_price;
+pub mod sell_price;
+
+pub const MAX_IDS_PER_REQUEST: usize = 200;
 
 pub fn get_prices(item_ids: &Vec<u32>) -> Result<Vec<PriceApiResponse>, ApiError> {
     debug!("[get_prices] started for {} items", item_ids.len());
 
-    get_sync(prices_path(item_ids))
-        .map_err(|e| ApiError::Unexpected(format!("Failed to fetch prices: {}", e)))
-        .and_then(|response| {
-            response
-                .into_json::<Vec<PriceApiResponse>>()
-                .map_err(|e| ApiError::Internal(format!("Failed to parse prices json: {}", e)))
-        })
+    match get_sync(prices_path(item_ids)) {
+        Ok(response) => response
+            .into_json::<Vec<PriceApiResponse>>()
+            .map_err(|e| ApiError::Internal(format!("Failed to parse prices json: {}", e))),
+        // none of the requested items is tradable
+        Err(ureq::Error::Status(404, _)) => Ok(vec![]),
+        Err(e) => Err(ApiError::from_request_error(e, "Failed to fetch prices")),
+    }
 }
 
 fn prices_path(ids: &[u32]) -> String {
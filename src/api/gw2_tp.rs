@@ -1,6 +1,9 @@
 use crate::service::http_client::get_sync;
 
 use crate::api::api_error::ApiError;
+use crate::api::gw2::price::buy_price::BuyPrice;
+use crate::api::gw2::price::price_api_response::PriceApiResponse;
+use crate::api::gw2::price::sell_price::SellPrice;
 use serde::Deserialize;
 
 const GW2TP_URL: &str = "https://api.gw2tp.com";
@@ -10,6 +13,12 @@ pub struct ItemsResponse {
     pub(crate) items: Vec<(u32, String)>,
 }
 
+#[derive(Deserialize, Debug)]
+struct BulkPricesResponse {
+    columns: Vec<String>,
+    items: Vec<Vec<Option<u32>>>,
+}
+
 fn items_name_path() -> String {
     format!("{}/1/bulk/items-names.json", GW2TP_URL)
 }
@@ -23,3 +32,40 @@ pub fn get_item_names() -> Result<ItemsResponse, ApiError> {
                 .map_err(|e| ApiError::Internal(format!("Failed to fetch json: {e}")))
         })
 }
+
+fn items_path() -> String {
+    format!("{}/1/bulk/items.json", GW2TP_URL)
+}
+
+pub fn get_bulk_prices() -> Result<Vec<PriceApiResponse>, ApiError> {
+    let response: BulkPricesResponse = get_sync(items_path())
+        .map_err(|e| ApiError::from_request_error(e, "Could not fetch bulk prices"))
+        .and_then(|response| {
+            response
+                .into_json()
+                .map_err(|e| ApiError::Internal(format!("Failed to fetch json: {e}")))
+        })?;
+    let column = |name: &str| {
+        response
+            .columns
+            .iter()
+            .position(|column| column == name)
+            .ok_or_else(|| ApiError::Internal(format!("Missing bulk prices column: {name}")))
+    };
+    let (id_column, buy_column, sell_column) = (column("id")?, column("buy")?, column("sell")?);
+    Ok(response
+        .items
+        .iter()
+        .filter_map(|row| {
+            Some(PriceApiResponse {
+                id: (*row.get(id_column)?)?,
+                buys: BuyPrice {
+                    unit_price: row.get(buy_column).copied().flatten().unwrap_or_default(),
+                },
+                sells: SellPrice {
+                    unit_price: row.get(sell_column).copied().flatten().unwrap_or_default(),
+                },
+            })
+        })
+        .collect())
+}
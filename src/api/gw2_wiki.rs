@@ -1,20 +1,52 @@
+use crate::api::api_error::ApiError;
 use crate::configuration::textures_dir;
+use crate::service::build_template::{
+    build_chat_code, prepare_build_template_popup, BuildTemplate,
+};
+use crate::service::chat_code::{decode_chat_code, ChatLink};
 use crate::service::http_client::get_sync;
+use crate::service::image_conversion::normalize_to_png;
+use crate::service::location::attach_chat_code;
 use crate::service::popup;
 use crate::service::popup::fill_popup_with_wiki_details;
+use crate::state::cache::item_name::find_item_name;
 use crate::state::cache::texture::identifier_to_filename;
 use crate::state::cache::StoreInCache;
-use crate::state::context::write_context;
+use crate::state::context::{read_context, write_context};
 use crate::state::popup::Popup;
 use crate::state::search::matching_entry::MatchingSearchEntry;
 use log::{debug, error, info, warn};
 use scraper::selectable::Selectable;
 use scraper::{ElementRef, Html, Selector};
-use std::fs::{self, File};
-use std::io::copy;
+use std::fmt::Display;
+use std::fs;
+use std::io::Read;
 
 const GW2_WIKI_URL: &str = "https://wiki.guildwars2.com";
 
+#[derive(Clone, Debug)]
+pub enum SearchContext {
+    Item,
+    Skill,
+    Trait,
+    Recipe,
+    Skin,
+    Outfit,
+}
+
+impl Display for SearchContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchContext::Item => write!(f, "Item"),
+            SearchContext::Skill => write!(f, "Skill"),
+            SearchContext::Trait => write!(f, "Trait"),
+            SearchContext::Recipe => write!(f, "Recipe"),
+            SearchContext::Skin => write!(f, "Skin"),
+            SearchContext::Outfit => write!(f, "Outfit"),
+        }
+    }
+}
+
 pub fn href_to_wiki_url(href: &String) -> String {
     debug!("[href_to_wiki_url] Formatting {href}");
     let result = format!("{}{}", GW2_WIKI_URL, href.replace('"', "%22"));
@@ -22,6 +54,45 @@ pub fn href_to_wiki_url(href: &String) -> String {
     result
 }
 
+// "/images/thumb/d/d1/Name.png/18px-Name.png" -> "/images/d/d1/Name.png"
+pub fn full_resolution_href(href: &str) -> String {
+    let Some(path) = href.strip_prefix("/images/thumb/") else {
+        return href.to_string();
+    };
+    match path.rsplit_once('/') {
+        Some((original, _)) => format!("/images/{original}"),
+        None => href.to_string(),
+    }
+}
+
+// "/images/thumb/d/d1/Name.png/18px-Name.png" -> Some(18)
+pub fn thumbnail_width(href: &str) -> Option<u32> {
+    let path = href.strip_prefix("/images/thumb/")?;
+    let (_, name) = path.rsplit_once('/')?;
+    name.split_once("px-")?.0.parse().ok()
+}
+
+// "/images/d/d1/Name.png", 32 -> "/images/thumb/d/d1/Name.png/32px-Name.png"
+pub fn sized_thumbnail_href(href: &str, width: u32) -> String {
+    if thumbnail_width(href) == Some(width) {
+        return href.to_string();
+    }
+    let original = full_resolution_href(href);
+    let Some(path) = original.strip_prefix("/images/") else {
+        return href.to_string();
+    };
+    let Some((_, name)) = path.rsplit_once('/') else {
+        return href.to_string();
+    };
+    // vector images are only ever thumbnailed to png
+    let extension = if name.to_ascii_lowercase().ends_with(".svg") {
+        ".png"
+    } else {
+        ""
+    };
+    format!("/images/thumb/{path}/{width}px-{name}{extension}")
+}
+
 pub fn prepare_item_popup_with_quantity(item_name: &str, item_quantity: &usize) -> Popup {
     debug!(
         "[prepare_item_popup] Preparing popup for item: {}",
@@ -61,27 +132,37 @@ pub fn prepare_item_popup_with_quantity(item_name: &str, item_quantity: &usize)
     popup
 }
 
-#[allow(clippy::result_large_err)]
-pub fn download_wiki_image(href: &String) -> Result<(), ureq::Error> {
-    let path = href_to_wiki_url(href);
+pub fn download_wiki_image(href: &String) -> Result<(), ApiError> {
+    download_wiki_image_as(href, href)
+}
+
+// Saves the image under the identifier's file, so a fallback can stand in for a missing thumb.
+pub fn download_wiki_image_as(href: &String, identifier: &str) -> Result<(), ApiError> {
+    // icons coming from the official API are already absolute urls
+    let path = if href.starts_with("https://") {
+        href.clone()
+    } else {
+        href_to_wiki_url(href)
+    };
     debug!("[download_wiki_image] Downloading image from: {}", path);
-    match get_sync(path) {
-        Ok(response) => {
-            let mut path = textures_dir();
-            let _ = fs::create_dir(&path);
-            path.push(identifier_to_filename(href));
-
-            debug!(
-                "[download_wiki_image] Saving image to \"{}\"",
-                path.display()
-            );
-
-            let mut file = File::create(&path)?;
-            copy(&mut response.into_reader(), &mut file)?;
-            Ok(())
-        }
-        Err(e) => Err(e),
-    }
+    let response =
+        get_sync(path).map_err(|e| ApiError::from_request_error(e, "Failed to download image"))?;
+    let mut bytes = vec![];
+    response
+        .into_reader()
+        .read_to_end(&mut bytes)
+        .map_err(|e| ApiError::Transient(format!("Failed to read image: {e}")))?;
+    let bytes = normalize_to_png(bytes).map_err(ApiError::Unexpected)?;
+
+    let mut path = textures_dir();
+    let _ = fs::create_dir(&path);
+    path.push(identifier_to_filename(identifier));
+    debug!(
+        "[download_wiki_image] Saving image to \"{}\"",
+        path.display()
+    );
+    fs::write(&path, bytes)
+        .map_err(|e| ApiError::Internal(format!("Failed to save \"{}\": {e}", path.display())))
 }
 
 fn fill_using_special_search(item_name: String, popup: &mut Popup) -> Option<Popup> {
@@ -117,18 +198,110 @@ fn fill_using_special_search(item_name: String, popup: &mut Popup) -> Option<Pop
 
 pub fn special_search_href(item_name: String, item_id: Option<u32>) -> String {
     if let Some(item_id) = item_id {
-        format!(
-            "/wiki/Special:RunQuery/Search_by_id?title=Special%3ARunQuery%2FSearch_by_id\
-            &pfRunQueryFormName=Search+by+id&Search_by_id=id%3D45105%26context%3DItem\
-            &wpRunQuery=&pf_free_text=\
-            &Search+by+id%5Bid%5D={item_id}\
-            &Search+by+id%5Bcontext%5D=Item&wpRunQuery=&pf_free_text={item_id}"
-        )
+        id_search_href(item_id, &SearchContext::Item)
     } else {
         format!("/index.php?search={item_name}&title=Special%3ASearch&profile=advanced&fulltext=1&ns0=1")
     }
 }
 
+pub fn id_search_href(id: u32, context: &SearchContext) -> String {
+    format!(
+        "/wiki/Special:RunQuery/Search_by_id?title=Special%3ARunQuery%2FSearch_by_id\
+        &pfRunQueryFormName=Search+by+id&Search_by_id=id%3D45105%26context%3D{context}\
+        &wpRunQuery=&pf_free_text=\
+        &Search+by+id%5Bid%5D={id}\
+        &Search+by+id%5Bcontext%5D={context}&wpRunQuery=&pf_free_text={id}"
+    )
+}
+
+pub fn prepare_chat_link_popup(chat_link: &ChatLink, chat_code: &str) -> Option<Popup> {
+    debug!("[prepare_chat_link_popup] Preparing popup for chat link: {chat_link:?}");
+    write_context().ui.loading_progress = Some(10);
+    match chat_link {
+        ChatLink::Item { id, quantity } => {
+            let item_name = read_context()
+                .cache
+                .item_names
+                .value()
+                .and_then(|item_names| find_item_name(item_names, *id));
+            match item_name {
+                Some(item_name) => Some(prepare_item_popup_with_quantity(
+                    &item_name,
+                    &(*quantity as usize),
+                )),
+                None => prepare_id_popup(*id, SearchContext::Item, *quantity as usize),
+            }
+        }
+        ChatLink::Skill(id) => prepare_id_popup(*id, SearchContext::Skill, 1),
+        ChatLink::Trait(id) => prepare_id_popup(*id, SearchContext::Trait, 1),
+        ChatLink::Recipe(id) => prepare_id_popup(*id, SearchContext::Recipe, 1),
+        ChatLink::Skin(id) => prepare_id_popup(*id, SearchContext::Skin, 1),
+        ChatLink::Outfit(id) => prepare_id_popup(*id, SearchContext::Outfit, 1),
+        ChatLink::Map(_) => prepare_location_popup(chat_code),
+        ChatLink::PvpGame(_) => prepare_chat_code_popup(chat_code),
+        ChatLink::BuildTemplate(build_template) => prepare_build_popup(build_template, chat_code),
+    }
+}
+
+fn prepare_build_popup(build_template: &BuildTemplate, chat_code: &str) -> Option<Popup> {
+    let mut popup = prepare_build_template_popup(build_template, chat_code)?;
+    let href = popup.data.href.clone();
+    write_context()
+        .cache
+        .popup_data_map
+        .store(&href, &mut popup.data);
+    Some(popup)
+}
+
+fn prepare_location_popup(chat_code: &str) -> Option<Popup> {
+    let mut popup = prepare_chat_code_popup(chat_code)?;
+    attach_chat_code(&mut popup, chat_code);
+    let href = popup.data.href.clone();
+    write_context()
+        .cache
+        .popup_data_map
+        .store(&href, &mut popup.data);
+    Some(popup)
+}
+
+fn prepare_id_popup(id: u32, context: SearchContext, item_quantity: usize) -> Option<Popup> {
+    let document = get_wiki_special_search(&id_search_href(id, &context))?;
+    write_context().ui.loading_progress = Some(50);
+    let search_entry = extract_search_results(&document, Some(id))
+        .into_iter()
+        .next()?;
+    let mut popup = prepare_href_popup(&search_entry.href, search_entry.text);
+    popup.state.item_quantity = item_quantity;
+    if matches!(context, SearchContext::Item) && popup.data.item_ids.is_none() {
+        popup.data.item_ids = Some(vec![id]);
+    }
+    Some(popup)
+}
+
+// Location infoboxes on the wiki list their chat codes, so a full text search finds the article.
+fn prepare_chat_code_popup(chat_code: &str) -> Option<Popup> {
+    let href = special_search_href(encode_query_value(chat_code), None);
+    let document = get_wiki_special_search(&href)?;
+    write_context().ui.loading_progress = Some(50);
+    let search_entry = extract_search_results(&document, None).into_iter().next()?;
+    Some(prepare_href_popup(&search_entry.href, search_entry.text))
+}
+
+fn encode_query_value(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '[' => "%5B".to_string(),
+            ']' => "%5D".to_string(),
+            '&' => "%26".to_string(),
+            '=' => "%3D".to_string(),
+            '+' => "%2B".to_string(),
+            '/' => "%2F".to_string(),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
 pub fn prepare_href_popup(href: &String, title: String) -> Popup {
     debug!(
         "[prepare_href_popup] Preparing popup for href: {} and title: {}",
@@ -142,6 +315,15 @@ pub fn prepare_href_popup(href: &String, title: String) -> Popup {
         }
         return Popup::new(cached_data);
     }
+    // builds aren't wiki articles, so they are decoded again from their chat code
+    if let Some(chat_code) = build_chat_code(href) {
+        if let Ok(ChatLink::BuildTemplate(build_template)) = decode_chat_code(chat_code) {
+            if let Some(popup) = prepare_build_popup(&build_template, chat_code) {
+                return popup;
+            }
+        }
+        return prepare_popup(href, title);
+    }
 
     let mut popup = prepare_popup(href, title);
     if let Some(document) = get_wiki_article(href) {
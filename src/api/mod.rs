@@ -1,4 +1,6 @@
-mod api_error;
+pub mod api_error;
+pub mod datawars2;
 pub mod gw2;
 pub mod gw2_tp;
 pub mod gw2_wiki;
+pub mod provider;
@@ -0,0 +1,268 @@
+use crate::api::api_error::ApiError;
+use crate::api::gw2::price::get_prices;
+use crate::api::gw2::price::price_api_response::PriceApiResponse;
+use crate::api::gw2_tp::{get_bulk_prices, get_item_names, ItemsResponse};
+use crate::configuration::provider_params::ProviderKind;
+use crate::configuration::{config_dir, read_config};
+use log::{debug, warn};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+const BULK_PRICES_EXPIRATION: Duration = Duration::from_secs(60);
+
+pub trait PriceProvider {
+    fn get_prices(&self, item_ids: &[u32]) -> Result<Vec<PriceApiResponse>, ApiError>;
+}
+
+pub trait ItemNameProvider {
+    fn get_item_names(&self) -> Result<ItemsResponse, ApiError>;
+}
+
+pub struct OfficialApiProvider;
+
+impl PriceProvider for OfficialApiProvider {
+    fn get_prices(&self, item_ids: &[u32]) -> Result<Vec<PriceApiResponse>, ApiError> {
+        get_prices(&item_ids.to_vec())
+    }
+}
+
+pub struct Gw2TpProvider;
+
+struct BulkPrices {
+    fetched_at: Instant,
+    prices: HashMap<u32, PriceApiResponse>,
+}
+
+// The bulk file holds every item, so it is shared between chunks and retries for a while.
+static BULK_PRICES: OnceLock<Mutex<Option<BulkPrices>>> = OnceLock::new();
+
+impl PriceProvider for Gw2TpProvider {
+    fn get_prices(&self, item_ids: &[u32]) -> Result<Vec<PriceApiResponse>, ApiError> {
+        // held during the download, so concurrent requests wait for a single fetch
+        let mut bulk_prices = BULK_PRICES
+            .get_or_init(|| Mutex::new(None))
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let prices = match bulk_prices.as_ref() {
+            Some(bulk) if bulk.fetched_at.elapsed() <= BULK_PRICES_EXPIRATION => &bulk.prices,
+            _ => {
+                debug!("[Gw2TpProvider] downloading bulk prices");
+                let prices = get_bulk_prices()?
+                    .into_iter()
+                    .map(|price| (price.id, price))
+                    .collect();
+                &bulk_prices
+                    .insert(BulkPrices {
+                        fetched_at: Instant::now(),
+                        prices,
+                    })
+                    .prices
+            }
+        };
+        Ok(item_ids
+            .iter()
+            .filter_map(|item_id| prices.get(item_id).cloned())
+            .collect())
+    }
+}
+
+impl ItemNameProvider for Gw2TpProvider {
+    fn get_item_names(&self) -> Result<ItemsResponse, ApiError> {
+        get_item_names()
+    }
+}
+
+// Reads a local snapshot from the config directory, for use without network access, e.g.
+// {"items": [[19721, "Glob of Ectoplasm"]],
+// "prices": [{"id": 19721, "buys": {"unit_price": 1}, "sells": {"unit_price": 2}}]}
+pub struct LocalFileProvider {
+    pub path: PathBuf,
+}
+
+#[derive(Deserialize, Debug)]
+struct Snapshot {
+    #[serde(default)]
+    items: Vec<(u32, String)>,
+    #[serde(default)]
+    prices: Vec<PriceApiResponse>,
+}
+
+impl LocalFileProvider {
+    pub fn new(file_name: &str) -> Self {
+        Self {
+            path: config_dir().join(file_name),
+        }
+    }
+
+    fn read_snapshot(&self) -> Result<Snapshot, ApiError> {
+        let path = &self.path;
+        let file = File::open(path).map_err(|e| {
+            ApiError::Unexpected(format!("Could not open \"{}\": {e}", path.display()))
+        })?;
+        serde_json::from_reader(BufReader::new(file))
+            .map_err(|e| ApiError::Internal(format!("Failed to parse \"{}\": {e}", path.display())))
+    }
+}
+
+impl PriceProvider for LocalFileProvider {
+    fn get_prices(&self, item_ids: &[u32]) -> Result<Vec<PriceApiResponse>, ApiError> {
+        Ok(self
+            .read_snapshot()?
+            .prices
+            .into_iter()
+            .filter(|price| item_ids.contains(&price.id))
+            .collect())
+    }
+}
+
+impl ItemNameProvider for LocalFileProvider {
+    fn get_item_names(&self) -> Result<ItemsResponse, ApiError> {
+        Ok(ItemsResponse {
+            items: self.read_snapshot()?.items,
+        })
+    }
+}
+
+fn price_provider(kind: ProviderKind, snapshot_file_name: &str) -> Box<dyn PriceProvider> {
+    match kind {
+        ProviderKind::OfficialApi => Box::new(OfficialApiProvider),
+        ProviderKind::Gw2Tp => Box::new(Gw2TpProvider),
+        ProviderKind::LocalFile => Box::new(LocalFileProvider::new(snapshot_file_name)),
+    }
+}
+
+// The official API has no bulk item name endpoint.
+fn item_name_provider(
+    kind: ProviderKind,
+    snapshot_file_name: &str,
+) -> Option<Box<dyn ItemNameProvider>> {
+    match kind {
+        ProviderKind::OfficialApi => None,
+        ProviderKind::Gw2Tp => Some(Box::new(Gw2TpProvider)),
+        ProviderKind::LocalFile => Some(Box::new(LocalFileProvider::new(snapshot_file_name))),
+    }
+}
+
+pub fn fetch_prices(item_ids: &[u32]) -> Result<Vec<PriceApiResponse>, ApiError> {
+    let provider_params = read_config().provider_params.clone();
+    let providers = provider_params.price_providers.into_iter().map(|kind| {
+        (
+            kind,
+            price_provider(kind, &provider_params.snapshot_file_name),
+        )
+    });
+    fetch_prices_from(providers, item_ids)
+}
+
+fn fetch_prices_from(
+    providers: impl IntoIterator<Item = (ProviderKind, Box<dyn PriceProvider>)>,
+    item_ids: &[u32],
+) -> Result<Vec<PriceApiResponse>, ApiError> {
+    let mut last_error = ApiError::Internal("No price provider configured".to_string());
+    for (kind, provider) in providers {
+        debug!("[fetch_prices] using {kind}");
+        match provider.get_prices(item_ids) {
+            Ok(prices) => return Ok(prices),
+            Err(api_error) => {
+                warn!("[fetch_prices] {kind} failed, trying the next provider");
+                api_error.log();
+                last_error = api_error;
+            }
+        }
+    }
+    Err(last_error)
+}
+
+pub fn fetch_item_names() -> Result<ItemsResponse, ApiError> {
+    let provider_params = read_config().provider_params.clone();
+    let mut last_error = ApiError::Internal("No item name provider configured".to_string());
+    for kind in provider_params.item_name_providers {
+        let Some(provider) = item_name_provider(kind, &provider_params.snapshot_file_name) else {
+            continue;
+        };
+        debug!("[fetch_item_names] using {kind}");
+        match provider.get_item_names() {
+            Ok(item_names) => return Ok(item_names),
+            Err(api_error) => {
+                warn!("[fetch_item_names] {kind} failed, trying the next provider");
+                api_error.log();
+                last_error = api_error;
+            }
+        }
+    }
+    Err(last_error)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn fixture_provider() -> LocalFileProvider {
+        LocalFileProvider {
+            path: PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures/price_snapshot.json"),
+        }
+    }
+
+    struct FailingProvider;
+
+    impl PriceProvider for FailingProvider {
+        fn get_prices(&self, _item_ids: &[u32]) -> Result<Vec<PriceApiResponse>, ApiError> {
+            Err(ApiError::Transient("Service unavailable".to_string()))
+        }
+    }
+
+    #[test]
+    fn local_file_provider_returns_requested_prices() {
+        let prices = fixture_provider().get_prices(&[19721, 1]).unwrap();
+
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices[0].id, 19721);
+        assert_eq!(prices[0].buys.unit_price, 2150);
+        assert_eq!(prices[0].sells.unit_price, 2304);
+    }
+
+    #[test]
+    fn local_file_provider_returns_item_names() {
+        let item_names = fixture_provider().get_item_names().unwrap();
+
+        assert!(item_names
+            .items
+            .contains(&(24, "Sealed Package of Snowballs".to_string())));
+    }
+
+    #[test]
+    fn fetch_prices_falls_back_when_provider_fails() {
+        let providers: Vec<(ProviderKind, Box<dyn PriceProvider>)> = vec![
+            (ProviderKind::Gw2Tp, Box::new(FailingProvider)),
+            (ProviderKind::LocalFile, Box::new(fixture_provider())),
+        ];
+
+        let prices = fetch_prices_from(providers, &[24]).unwrap();
+
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices[0].sells.unit_price, 161);
+    }
+
+    #[test]
+    fn fetch_prices_returns_last_error_when_all_providers_fail() {
+        let providers: Vec<(ProviderKind, Box<dyn PriceProvider>)> = vec![
+            (ProviderKind::Gw2Tp, Box::new(FailingProvider)),
+            (
+                ProviderKind::LocalFile,
+                Box::new(LocalFileProvider {
+                    path: PathBuf::from("missing_snapshot.json"),
+                }),
+            ),
+        ];
+
+        let result = fetch_prices_from(providers, &[24]);
+
+        assert!(matches!(result, Err(ApiError::Unexpected(_))));
+    }
+}
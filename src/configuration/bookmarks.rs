@@ -0,0 +1,72 @@
+use crate::state::popup::token::Token;
+use crate::state::popup::Popup;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub href: String,
+    pub title: String,
+    #[serde(default)]
+    pub icon_href: Option<String>,
+    #[serde(default)]
+    pub item_ids: Option<Vec<u32>>,
+    #[serde(default)]
+    pub folder: Option<String>,
+}
+
+impl Bookmark {
+    pub fn from_popup(popup: &Popup) -> Self {
+        let icon_href = match &popup.data.item_icon {
+            Some(Token::Image(href, _)) => Some(href.clone()),
+            _ => None,
+        };
+        Self {
+            href: popup.data.href.clone(),
+            title: popup.data.title.clone(),
+            icon_href,
+            item_ids: popup.data.item_ids.clone(),
+            folder: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Bookmarks {
+    #[serde(default)]
+    pub entries: Vec<Bookmark>,
+
+    #[serde(default)]
+    pub folders: Vec<String>,
+}
+
+impl Bookmarks {
+    pub fn contains(&self, href: &str) -> bool {
+        self.entries.iter().any(|bookmark| bookmark.href == href)
+    }
+
+    pub fn toggle(&mut self, popup: &Popup) {
+        if self.contains(&popup.data.href) {
+            self.entries
+                .retain(|bookmark| bookmark.href != popup.data.href);
+        } else {
+            self.entries.push(Bookmark::from_popup(popup));
+        }
+    }
+
+    pub fn add_folder(&mut self, name: &str) {
+        let name = name.trim();
+        if !name.is_empty() && !self.folders.iter().any(|folder| folder == name) {
+            self.folders.push(name.to_string());
+        }
+    }
+
+    // Bookmarks of a removed folder are kept, outside of any folder.
+    pub fn remove_folder(&mut self, name: &str) {
+        self.folders.retain(|folder| folder != name);
+        for bookmark in &mut self.entries {
+            if bookmark.folder.as_deref() == Some(name) {
+                bookmark.folder = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::state::popup::Popup;
+
+pub type LayoutName = String;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedPopupLayout {
+    pub href: String,
+    pub title: String,
+    pub item_quantity: usize,
+    pub pos: Option<[f32; 2]>,
+    pub width: Option<f32>,
+    pub collapsed: bool,
+    pub selected_tab: Option<String>,
+}
+
+impl PinnedPopupLayout {
+    pub fn from_popup(popup: &Popup) -> Self {
+        Self {
+            href: popup.data.href.clone(),
+            title: popup.data.title.clone(),
+            item_quantity: popup.state.item_quantity,
+            pos: popup.state.pos,
+            width: popup.state.width,
+            collapsed: popup.state.collapsed,
+            selected_tab: popup.state.selected_tab.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Layouts {
+    // pinned popups of the last session
+    #[serde(default)]
+    pub pinned_popups: Vec<PinnedPopupLayout>,
+    #[serde(default)]
+    pub saved: BTreeMap<LayoutName, Vec<PinnedPopupLayout>>,
+}
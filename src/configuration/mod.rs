@@ -1,14 +1,26 @@
 pub mod popup {
     pub mod rendering_params;
+    pub mod tab_rules;
 }
+pub mod bookmarks;
 pub mod keyboard_layout;
+pub mod layouts;
 pub mod notification_params;
+pub mod price_history_source;
+pub mod provider_params;
 pub(crate) mod search;
+pub mod watchlist;
 
 use crate::addon::PACKAGE_VERSION;
+use crate::configuration::bookmarks::Bookmarks;
 use crate::configuration::keyboard_layout::KeyboardLayout;
+use crate::configuration::layouts::Layouts;
 use crate::configuration::notification_params::NotificationParams;
 use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::configuration::price_history_source::PriceHistorySource;
+use crate::configuration::provider_params::ProviderParams;
+use crate::configuration::watchlist::Watchlist;
+use crate::service::layout::restore_layout;
 use crate::state::cache::Persist;
 use crate::state::context::write_context;
 use crate::utils::serde::{no, yes};
@@ -16,6 +28,7 @@ use log::{info, trace, warn};
 use nexus::paths::get_addon_dir;
 use search::search_params::SearchParams;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
@@ -72,6 +85,25 @@ pub struct Config {
 
     #[serde(default = "SearchParams::default")]
     pub search_params: SearchParams,
+
+    #[serde(default = "PriceHistorySource::default")]
+    pub price_history_source: PriceHistorySource,
+
+    #[serde(default = "Watchlist::default")]
+    pub watchlist: Watchlist,
+
+    #[serde(default = "ProviderParams::default")]
+    pub provider_params: ProviderParams,
+
+    // item name, item id chosen to drive the header price
+    #[serde(default)]
+    pub preferred_item_variants: HashMap<String, u32>,
+
+    #[serde(default)]
+    pub layouts: Layouts,
+
+    #[serde(default)]
+    pub bookmarks: Bookmarks,
 }
 
 impl Default for Config {
@@ -93,6 +125,12 @@ impl Default for Config {
             keyboard_layout: KeyboardLayout::default(),
             notification_params: NotificationParams::default(),
             search_params: SearchParams::default(),
+            price_history_source: PriceHistorySource::default(),
+            watchlist: Watchlist::default(),
+            provider_params: ProviderParams::default(),
+            preferred_item_variants: HashMap::new(),
+            layouts: Layouts::default(),
+            bookmarks: Bookmarks::default(),
         }
     }
 }
@@ -113,6 +151,7 @@ impl Persist for Config {
             .ok();
         if let Some(config) = config_opt {
             *self = config;
+            self.rendering_params.migrate_blacklisted_tabs();
         }
         info!("[load] Loaded config from \"{}\"", path.display());
     }
@@ -171,6 +210,11 @@ pub(crate) fn load_config_files() {
         write_config().load();
         write_context().cache.popup_data_map.load();
         write_context().cache.item_names.load();
+        write_context().cache.professions.load();
+        write_context().cache.price_history.load();
+        write_context().cache.notes.load();
+        let pinned_popups = read_config().layouts.pinned_popups.clone();
+        restore_layout(&mut write_context(), &pinned_popups);
     }
 }
 
@@ -184,6 +228,12 @@ pub fn textures_dir() -> PathBuf {
     result
 }
 
+pub fn saved_images_dir() -> PathBuf {
+    let mut result = get_addon_dir("item_detail_popups").expect("invalid config directory");
+    result.push("saved_images");
+    result
+}
+
 pub fn fonts_dir() -> PathBuf {
     let mut result = get_addon_dir("item_detail_popups").expect("invalid fonts directory");
     result.push("..\\..\\fonts");
@@ -1,8 +1,11 @@
+use crate::configuration::popup::tab_rules::TabRules;
 use crate::utils::serde::{no, yes};
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_MAX_CONTENT_WIDTH: f32 = 800.0;
 const DEFAULT_MAX_CONTENT_HEIGHT: f32 = 350.0;
+const DEFAULT_LISTINGS_DEPTH: usize = 10;
+const DEFAULT_TAG_PREVIEW_DELAY_MS: u64 = 400;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RenderingParams {
@@ -27,8 +30,12 @@ pub struct RenderingParams {
     #[serde(default = "yes")]
     pub show_images_tab: bool,
 
-    #[serde(default = "default_blacklisted_tabs")]
-    pub blacklisted_tabs: Vec<String>,
+    #[serde(default = "TabRules::default")]
+    pub tab_rules: TabRules,
+
+    // replaced by tab_rules, only read to migrate older configs
+    #[serde(default, skip_serializing)]
+    pub(crate) blacklisted_tabs: Vec<String>,
 
     #[serde(default = "yes")]
     pub show_tag_bar: bool,
@@ -44,6 +51,15 @@ pub struct RenderingParams {
 
     #[serde(default = "default_max_content_height")]
     pub max_content_height: f32,
+
+    #[serde(default = "default_listings_depth")]
+    pub listings_depth: usize,
+
+    #[serde(default = "yes")]
+    pub show_tag_previews: bool,
+
+    #[serde(default = "default_tag_preview_delay_ms")]
+    pub tag_preview_delay_ms: u64,
 }
 
 impl Default for RenderingParams {
@@ -55,29 +71,28 @@ impl Default for RenderingParams {
             copper_coin_color: default_copper_coin_color(),
             use_bullet_list_punctuation: yes(),
             show_general_tab: yes(),
-            blacklisted_tabs: default_blacklisted_tabs(),
+            tab_rules: TabRules::default(),
+            blacklisted_tabs: vec![],
             show_images_tab: yes(),
             show_tag_bar: yes(),
             auto_pin_on_tab_hover: yes(),
             allow_popup_collapsing: no(),
             max_content_width: default_max_content_width(),
             max_content_height: default_max_content_height(),
+            listings_depth: default_listings_depth(),
+            show_tag_previews: yes(),
+            tag_preview_delay_ms: default_tag_preview_delay_ms(),
         }
     }
 }
 
-fn default_blacklisted_tabs() -> Vec<String> {
-    vec![
-        "external links".to_string(),
-        "references".to_string(),
-        "trivia".to_string(),
-        "dialogue".to_string(),
-        "quotes".to_string(),
-        "version history".to_string(),
-        "gallery".to_string(),
-        "gem store history".to_string(),
-        "text".to_string(),
-    ]
+impl RenderingParams {
+    pub fn migrate_blacklisted_tabs(&mut self) {
+        if !self.blacklisted_tabs.is_empty() {
+            self.tab_rules = TabRules::from_blacklisted_tabs(&self.blacklisted_tabs);
+            self.blacklisted_tabs.clear();
+        }
+    }
 }
 
 fn default_link_color() -> [f32; 4] {
@@ -103,3 +118,11 @@ pub fn default_max_content_width() -> f32 {
 pub fn default_max_content_height() -> f32 {
     DEFAULT_MAX_CONTENT_HEIGHT
 }
+
+fn default_listings_depth() -> usize {
+    DEFAULT_LISTINGS_DEPTH
+}
+
+fn default_tag_preview_delay_ms() -> u64 {
+    DEFAULT_TAG_PREVIEW_DELAY_MS
+}
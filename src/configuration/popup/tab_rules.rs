@@ -0,0 +1,214 @@
+use crate::state::popup::popup_data::{PopupData, SectionName};
+use crate::state::popup::token::Token;
+use crate::utils::serde::yes;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use strum_macros::EnumIter;
+
+const ITEM_INFOBOX_KEYS: [&str; 2] = ["Rarity", "Binding"];
+const SKILL_INFOBOX_KEYS: [&str; 3] = ["Recharge", "Activation", "Skill type"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, EnumIter, PartialEq, Eq, Hash, Default)]
+pub enum ArticleType {
+    #[default]
+    Any,
+    Item,
+    Skill,
+    Location,
+    Other,
+}
+
+impl ArticleType {
+    pub fn detect(data: &PopupData) -> Self {
+        let has_any = |keys: &[&str]| keys.iter().any(|key| data.infobox.contains_key(*key));
+        if data.location.is_some() {
+            ArticleType::Location
+        } else if data.item_ids.is_some() || has_any(&ITEM_INFOBOX_KEYS) {
+            ArticleType::Item
+        } else if has_any(&SKILL_INFOBOX_KEYS) {
+            ArticleType::Skill
+        } else {
+            ArticleType::Other
+        }
+    }
+}
+
+impl Display for ArticleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArticleType::Any => write!(f, "Any article"),
+            ArticleType::Item => write!(f, "Items"),
+            ArticleType::Skill => write!(f, "Skills"),
+            ArticleType::Location => write!(f, "Locations"),
+            ArticleType::Other => write!(f, "Other articles"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, EnumIter, PartialEq, Default)]
+pub enum TabRuleAction {
+    #[default]
+    Pin,
+    Hide,
+}
+
+impl Display for TabRuleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TabRuleAction::Pin => write!(f, "Show first"),
+            TabRuleAction::Hide => write!(f, "Hide"),
+        }
+    }
+}
+
+// A pattern matches a tab by name, ignoring case; '*' matches any text, e.g. "gem store*".
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TabRule {
+    pub pattern: String,
+    pub action: TabRuleAction,
+    #[serde(default)]
+    pub article_type: ArticleType,
+}
+
+impl TabRule {
+    pub fn new(pattern: &str, action: TabRuleAction) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            action,
+            article_type: ArticleType::Any,
+        }
+    }
+
+    pub fn matches(&self, tab_name: &str, article_type: &ArticleType) -> bool {
+        (self.article_type == ArticleType::Any || self.article_type == *article_type)
+            && matches_pattern(&self.pattern.to_lowercase(), &tab_name.to_lowercase())
+    }
+}
+
+// Rules are checked in order and the first matching one decides what happens with a tab.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TabRules {
+    #[serde(default = "default_tab_rules")]
+    pub rules: Vec<TabRule>,
+
+    #[serde(default = "yes")]
+    pub remember_selected_tab: bool,
+
+    #[serde(default)]
+    pub selected_tabs: HashMap<ArticleType, String>,
+}
+
+impl Default for TabRules {
+    fn default() -> Self {
+        Self {
+            rules: default_tab_rules(),
+            remember_selected_tab: yes(),
+            selected_tabs: HashMap::new(),
+        }
+    }
+}
+
+impl TabRules {
+    pub fn from_blacklisted_tabs(blacklisted_tabs: &[String]) -> Self {
+        let mut rules = default_pinned_tabs();
+        rules.extend(
+            blacklisted_tabs
+                .iter()
+                .map(|tab| TabRule::new(tab, TabRuleAction::Hide)),
+        );
+        Self {
+            rules,
+            ..Self::default()
+        }
+    }
+
+    fn matching_rule(
+        &self,
+        tab_name: &str,
+        article_type: &ArticleType,
+    ) -> Option<(usize, &TabRule)> {
+        self.rules
+            .iter()
+            .enumerate()
+            .find(|(_, rule)| rule.matches(tab_name, article_type))
+    }
+
+    pub fn is_hidden(&self, tab_name: &str, article_type: &ArticleType) -> bool {
+        self.matching_rule(tab_name, article_type)
+            .is_some_and(|(_, rule)| rule.action == TabRuleAction::Hide)
+    }
+
+    // Visible sections with the pinned ones first, in rule order, then the rest in article order.
+    pub fn ordered_sections<'a>(
+        &self,
+        sections: &'a IndexMap<SectionName, Vec<Token>>,
+        article_type: &ArticleType,
+    ) -> Vec<(&'a SectionName, &'a Vec<Token>)> {
+        let mut result: Vec<(usize, &'a SectionName, &'a Vec<Token>)> = sections
+            .iter()
+            .filter_map(|(section_name, tokens)| {
+                match self.matching_rule(section_name, article_type) {
+                    Some((_, rule)) if rule.action == TabRuleAction::Hide => None,
+                    Some((index, _)) => Some((index, section_name, tokens)),
+                    None => Some((self.rules.len(), section_name, tokens)),
+                }
+            })
+            .collect();
+        result.sort_by_key(|(order, _, _)| *order);
+        result
+            .into_iter()
+            .map(|(_, section_name, tokens)| (section_name, tokens))
+            .collect()
+    }
+}
+
+fn matches_pattern(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or("");
+    if !pattern.contains('*') {
+        return text == first;
+    }
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    for (index, part) in parts.iter().enumerate() {
+        if index == parts.len() - 1 {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+fn default_pinned_tabs() -> Vec<TabRule> {
+    ["Acquisition", "Used in", "Recipe"]
+        .iter()
+        .map(|tab| TabRule::new(tab, TabRuleAction::Pin))
+        .collect()
+}
+
+fn default_tab_rules() -> Vec<TabRule> {
+    let mut rules = default_pinned_tabs();
+    rules.extend(
+        [
+            "External links",
+            "References",
+            "Trivia",
+            "Dialogue",
+            "Quotes",
+            "Version history",
+            "Gallery",
+            "Gem store history",
+            "Text",
+        ]
+        .iter()
+        .map(|tab| TabRule::new(tab, TabRuleAction::Hide)),
+    );
+    rules
+}
@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Debug, Clone, Serialize, Deserialize, EnumIter, PartialEq, Default)]
+pub enum PriceHistorySource {
+    #[default]
+    LocalOnly,
+    DataWars2,
+}
+
+impl Display for PriceHistorySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PriceHistorySource::LocalOnly => write!(f, "Local only"),
+            PriceHistorySource::DataWars2 => write!(f, "Local + DataWars2 seed"),
+        }
+    }
+}
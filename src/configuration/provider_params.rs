@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use strum_macros::EnumIter;
+
+pub const DEFAULT_SNAPSHOT_FILE_NAME: &str = "market_snapshot.json";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumIter, PartialEq)]
+pub enum ProviderKind {
+    OfficialApi,
+    Gw2Tp,
+    LocalFile,
+}
+
+impl Display for ProviderKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProviderKind::OfficialApi => write!(f, "Official API"),
+            ProviderKind::Gw2Tp => write!(f, "gw2tp.com"),
+            ProviderKind::LocalFile => write!(f, "Local file"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderParams {
+    // tried in order, the next one is used when a provider fails
+    #[serde(default = "default_price_providers")]
+    pub price_providers: Vec<ProviderKind>,
+
+    #[serde(default = "default_item_name_providers")]
+    pub item_name_providers: Vec<ProviderKind>,
+
+    #[serde(default = "default_snapshot_file_name")]
+    pub snapshot_file_name: String,
+}
+
+impl ProviderParams {
+    pub fn set_primary_price_provider(&mut self, provider: ProviderKind) {
+        move_to_front(&mut self.price_providers, provider);
+    }
+
+    pub fn set_primary_item_name_provider(&mut self, provider: ProviderKind) {
+        move_to_front(&mut self.item_name_providers, provider);
+    }
+}
+
+impl Default for ProviderParams {
+    fn default() -> Self {
+        Self {
+            price_providers: default_price_providers(),
+            item_name_providers: default_item_name_providers(),
+            snapshot_file_name: default_snapshot_file_name(),
+        }
+    }
+}
+
+fn move_to_front(providers: &mut Vec<ProviderKind>, provider: ProviderKind) {
+    providers.retain(|p| *p != provider);
+    providers.insert(0, provider);
+}
+
+fn default_price_providers() -> Vec<ProviderKind> {
+    vec![ProviderKind::OfficialApi, ProviderKind::Gw2Tp]
+}
+
+fn default_item_name_providers() -> Vec<ProviderKind> {
+    vec![ProviderKind::Gw2Tp]
+}
+
+fn default_snapshot_file_name() -> String {
+    DEFAULT_SNAPSHOT_FILE_NAME.to_string()
+}
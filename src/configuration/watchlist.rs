@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub const DEFAULT_WATCHLIST_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchlistEntry {
+    pub item_id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub buy_threshold: Option<u32>,
+    #[serde(default)]
+    pub sell_threshold: Option<u32>,
+}
+
+impl WatchlistEntry {
+    pub fn buy_threshold_crossed(&self, previous: u32, current: u32) -> bool {
+        threshold_crossed(self.buy_threshold, previous, current)
+    }
+
+    pub fn sell_threshold_crossed(&self, previous: u32, current: u32) -> bool {
+        threshold_crossed(self.sell_threshold, previous, current)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Watchlist {
+    #[serde(default)]
+    pub entries: Vec<WatchlistEntry>,
+
+    #[serde(default = "default_poll_interval")]
+    pub poll_interval: Duration,
+}
+
+impl Watchlist {
+    pub fn add(&mut self, item_id: u32, name: String) {
+        if self.contains(item_id) {
+            return;
+        }
+        self.entries.push(WatchlistEntry {
+            item_id,
+            name,
+            buy_threshold: None,
+            sell_threshold: None,
+        });
+    }
+
+    pub fn remove(&mut self, item_id: u32) {
+        self.entries.retain(|entry| entry.item_id != item_id);
+    }
+
+    pub fn contains(&self, item_id: u32) -> bool {
+        self.entries.iter().any(|entry| entry.item_id == item_id)
+    }
+
+    pub fn item_ids(&self) -> Vec<u32> {
+        self.entries.iter().map(|entry| entry.item_id).collect()
+    }
+}
+
+impl Default for Watchlist {
+    fn default() -> Self {
+        Self {
+            entries: vec![],
+            poll_interval: DEFAULT_WATCHLIST_POLL_INTERVAL,
+        }
+    }
+}
+
+fn threshold_crossed(threshold: Option<u32>, previous: u32, current: u32) -> bool {
+    threshold.is_some_and(|threshold| (previous < threshold) != (current < threshold))
+}
+
+fn default_poll_interval() -> Duration {
+    DEFAULT_WATCHLIST_POLL_INTERVAL
+}
@@ -0,0 +1,258 @@
+use crate::configuration::bookmarks::Bookmark;
+use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::configuration::{read_config, write_config};
+use crate::render::popup_data::price::{highest_sell_item_id, priced_item_ids};
+use crate::render::ui::UiExtended;
+use crate::state::cache::{Cache, StoreInCache};
+use crate::state::context::Context;
+use crate::state::popup::dimensions::Dimensions;
+use crate::state::popup::Popup;
+use crate::state::threads::link::open_pinned_link_thread;
+use log::debug;
+use nexus::imgui::{Condition, MenuItem, MouseButton, TableFlags, TreeNodeFlags, Ui, Window};
+
+const BOOKMARKS_WINDOW_SIZE: [f32; 2] = [520.0, 320.0];
+const FOLDER_INPUT_WIDTH: f32 = 200.0;
+const BOOKMARK_TOGGLE: &str = "[*]";
+const BOOKMARKED_COLOR: [f32; 4] = [1.0, 0.843, 0.0, 1.0];
+
+enum BookmarkChange {
+    Remove(String),
+    Move(String, Option<String>),
+}
+
+impl Context {
+    pub fn render_bookmark_toggle(ui: &Ui, popup: &Popup, height: Option<f32>) {
+        if let Some(height) = height {
+            ui.align_vert_centered(BOOKMARK_TOGGLE, &height);
+        }
+        let bookmarked = read_config().bookmarks.contains(&popup.data.href);
+        if bookmarked {
+            ui.text_colored(BOOKMARKED_COLOR, BOOKMARK_TOGGLE);
+        } else {
+            ui.text_disabled(BOOKMARK_TOGGLE);
+        }
+        if ui.is_item_hovered() {
+            ui.tooltip_text(if bookmarked {
+                "Remove bookmark"
+            } else {
+                "Add bookmark"
+            });
+        }
+        if ui.is_item_clicked() {
+            write_config().bookmarks.toggle(popup);
+        }
+        ui.same_line();
+    }
+
+    pub fn render_bookmarks(&mut self, ui: &Ui) {
+        if !self.ui.bookmarks_opened {
+            return;
+        }
+        debug!("[render_bookmarks]");
+        let rendering_params = read_config().rendering_params.clone();
+        let bookmarks = read_config().bookmarks.clone();
+        let mut opened = self.ui.bookmarks_opened;
+        let mut changes = vec![];
+        let mut removed_folder = None;
+        let cache = &mut self.cache;
+        let folder_input = &mut self.ui.bookmark_folder_input;
+        Window::new("Bookmarks##idp")
+            .size(BOOKMARKS_WINDOW_SIZE, Condition::FirstUseEver)
+            .opened(&mut opened)
+            .build(ui, || {
+                ui.set_next_item_width(FOLDER_INPUT_WIDTH);
+                ui.input_text("##bookmark_folder_idp", folder_input)
+                    .hint("Folder name")
+                    .build();
+                ui.same_line();
+                if ui.button("Add folder##idp") {
+                    write_config().bookmarks.add_folder(folder_input);
+                    folder_input.clear();
+                }
+                if bookmarks.entries.is_empty() {
+                    ui.text_disabled(format!(
+                        "Bookmark articles by clicking {BOOKMARK_TOGGLE} in a popup title bar."
+                    ));
+                }
+                let unsorted: Vec<&Bookmark> = bookmarks
+                    .entries
+                    .iter()
+                    .filter(|bookmark| bookmark.folder.is_none())
+                    .collect();
+                render_bookmark_table(
+                    ui,
+                    "unsorted",
+                    &unsorted,
+                    &bookmarks.folders,
+                    cache,
+                    &rendering_params,
+                    &mut changes,
+                );
+                for folder in &bookmarks.folders {
+                    let entries: Vec<&Bookmark> = bookmarks
+                        .entries
+                        .iter()
+                        .filter(|bookmark| bookmark.folder.as_ref() == Some(folder))
+                        .collect();
+                    let header_opened = ui.collapsing_header(
+                        format!("{folder} ({})##bookmark_folder_idp{folder}", entries.len()),
+                        TreeNodeFlags::SPAN_AVAIL_WIDTH | TreeNodeFlags::DEFAULT_OPEN,
+                    );
+                    if ui.is_item_clicked_with_button(MouseButton::Right) {
+                        ui.open_popup(format!("##bookmark_folder_menu_idp{folder}"));
+                    }
+                    ui.popup(format!("##bookmark_folder_menu_idp{folder}"), || {
+                        if MenuItem::new(format!("Delete folder##idp{folder}")).build(ui) {
+                            removed_folder = Some(folder.clone());
+                        }
+                    });
+                    if header_opened {
+                        if entries.is_empty() {
+                            ui.text_disabled("Empty folder, right-click its name to delete it.");
+                        }
+                        render_bookmark_table(
+                            ui,
+                            folder,
+                            &entries,
+                            &bookmarks.folders,
+                            cache,
+                            &rendering_params,
+                            &mut changes,
+                        );
+                    }
+                }
+            });
+        self.ui.bookmarks_opened = opened;
+        let mut config = write_config();
+        if let Some(folder) = removed_folder {
+            config.bookmarks.remove_folder(&folder);
+        }
+        for change in changes {
+            match change {
+                BookmarkChange::Remove(href) => config
+                    .bookmarks
+                    .entries
+                    .retain(|bookmark| bookmark.href != href),
+                BookmarkChange::Move(href, folder) => {
+                    if let Some(bookmark) = config
+                        .bookmarks
+                        .entries
+                        .iter_mut()
+                        .find(|bookmark| bookmark.href == href)
+                    {
+                        bookmark.folder = folder;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_bookmark_table(
+    ui: &Ui,
+    id: &str,
+    bookmarks: &[&Bookmark],
+    folders: &[String],
+    cache: &mut Cache,
+    rendering_params: &RenderingParams,
+    changes: &mut Vec<BookmarkChange>,
+) {
+    if bookmarks.is_empty() {
+        return;
+    }
+    let Some(_t) = ui.begin_table_with_flags(
+        format!("bookmarks_{id}##idp"),
+        4,
+        TableFlags::RESIZABLE | TableFlags::NO_SAVED_SETTINGS | TableFlags::ROW_BG,
+    ) else {
+        return;
+    };
+    for header in ["Article", "Sell", "Buy", ""] {
+        ui.table_setup_column(header);
+    }
+    ui.table_headers_row();
+    let bookmark_item_ids: Vec<Option<Vec<u32>>> = bookmarks
+        .iter()
+        .map(|bookmark| {
+            // bookmarks added before the item names were cached have no ids stored
+            let item_ids = match &bookmark.item_ids {
+                Some(item_ids) => Some(item_ids.clone()),
+                None => cache
+                    .item_names
+                    .retrieve(())
+                    .and_then(|item_names| item_names.get(&bookmark.title).cloned()),
+            };
+            priced_item_ids(&bookmark.title, &item_ids)
+        })
+        .collect();
+    let item_ids: Vec<u32> = bookmark_item_ids
+        .iter()
+        .flatten()
+        .flatten()
+        .copied()
+        .collect();
+    let prices = cache.prices.retrieve(item_ids).unwrap_or_default();
+    for (bookmark, item_ids) in bookmarks.iter().zip(&bookmark_item_ids) {
+        ui.table_next_row();
+        ui.table_next_column();
+        if let Some(icon_href) = &bookmark.icon_href {
+            Context::render_image(ui, icon_href, &Some(Dimensions::small()), cache);
+            ui.same_line();
+        }
+        ui.text_colored(rendering_params.link_color, &bookmark.title);
+        if ui.is_item_clicked() {
+            open_pinned_link_thread(
+                bookmark.href.clone(),
+                bookmark.title.clone(),
+                ui.io().mouse_pos,
+            );
+        }
+        let price = item_ids
+            .as_ref()
+            .and_then(|item_ids| {
+                let item_prices = item_ids
+                    .iter()
+                    .filter_map(|item_id| Some((*item_id, prices.get(item_id)?.clone())))
+                    .collect();
+                highest_sell_item_id(&item_prices).and_then(|item_id| prices.get(&item_id))
+            })
+            .and_then(|cached_price| cached_price.value())
+            .cloned();
+        ui.table_next_column();
+        if let Some(price) = &price {
+            Context::render_price(ui, price.lowest_sell, cache, rendering_params);
+        }
+        ui.table_next_column();
+        if let Some(price) = &price {
+            Context::render_price(ui, price.highest_buy, cache, rendering_params);
+        }
+        ui.table_next_column();
+        if !folders.is_empty() && ui.small_button(format!("Move..##bookmark_idp{}", bookmark.href))
+        {
+            ui.open_popup(format!("##bookmark_move_idp{}", bookmark.href));
+        }
+        ui.popup(format!("##bookmark_move_idp{}", bookmark.href), || {
+            if bookmark.folder.is_some()
+                && MenuItem::new(format!("No folder##bookmark_idp{}", bookmark.href)).build(ui)
+            {
+                changes.push(BookmarkChange::Move(bookmark.href.clone(), None));
+            }
+            for folder in folders {
+                if bookmark.folder.as_ref() == Some(folder) {
+                    continue;
+                }
+                if MenuItem::new(format!("{folder}##bookmark_idp{}", bookmark.href)).build(ui) {
+                    changes.push(BookmarkChange::Move(
+                        bookmark.href.clone(),
+                        Some(folder.clone()),
+                    ));
+                }
+            }
+        });
+        ui.same_line();
+        if ui.small_button(format!("Remove##bookmark_idp{}", bookmark.href)) {
+            changes.push(BookmarkChange::Remove(bookmark.href.clone()));
+        }
+    }
+}
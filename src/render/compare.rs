@@ -0,0 +1,185 @@
+use crate::configuration::read_config;
+use crate::render::popup_data::price::{highest_sell_item_id, priced_item_ids};
+use crate::state::cache::StoreInCache;
+use crate::state::context::Context;
+use crate::state::popup::Popup;
+use indexmap::IndexSet;
+use log::debug;
+use nexus::imgui::{Condition, MenuItem, TableFlags, Ui, Window};
+
+const COMPARE_WINDOW_SIZE: [f32; 2] = [640.0, 400.0];
+const DIFFERENCE_COLOR: [f32; 4] = [1.0, 0.75, 0.2, 1.0];
+
+impl Context {
+    pub fn render_compare(&mut self, ui: &Ui) {
+        if !self.ui.compare_opened {
+            return;
+        }
+        debug!("[render_compare]");
+        let rendering_params = read_config().rendering_params.clone();
+        let pinned_popups = &self.ui.pinned_popups;
+        self.ui
+            .compared_popup_ids
+            .retain(|id| pinned_popups.iter().any(|popup| popup.state.id == *id));
+        let mut compared_popup_ids = self.ui.compared_popup_ids.clone();
+        let mut opened = self.ui.compare_opened;
+        Window::new("Compare##idp")
+            .size(COMPARE_WINDOW_SIZE, Condition::FirstUseEver)
+            .opened(&mut opened)
+            .build(ui, || {
+                if ui.small_button("Add popup..##compare_idp") {
+                    ui.open_popup("##compare_add_idp");
+                }
+                ui.popup("##compare_add_idp", || {
+                    for popup in pinned_popups {
+                        if compared_popup_ids.contains(&popup.state.id) {
+                            continue;
+                        }
+                        if MenuItem::new(format!(
+                            "{}##compare_idp{}",
+                            popup.data.title, popup.state.id
+                        ))
+                        .build(ui)
+                        {
+                            compared_popup_ids.push(popup.state.id);
+                        }
+                    }
+                });
+                let popups: Vec<&Popup> = compared_popup_ids
+                    .iter()
+                    .filter_map(|id| pinned_popups.iter().find(|popup| popup.state.id == *id))
+                    .collect();
+                if popups.len() < 2 {
+                    ui.text_disabled("Add another pinned popup to compare.");
+                }
+                if popups.is_empty() {
+                    return;
+                }
+                let Some(_t) = ui.begin_table_with_flags(
+                    "compare##idp",
+                    popups.len() + 1,
+                    TableFlags::RESIZABLE
+                        | TableFlags::NO_SAVED_SETTINGS
+                        | TableFlags::ROW_BG
+                        | TableFlags::BORDERS_INNER_V,
+                ) else {
+                    return;
+                };
+                ui.table_setup_column("");
+                for popup in &popups {
+                    ui.table_setup_column(format!(
+                        "{}##compare_idp{}",
+                        popup.data.title, popup.state.id
+                    ));
+                }
+                ui.table_headers_row();
+
+                let mut removed_popup_id = None;
+                ui.table_next_row();
+                ui.table_next_column();
+                for popup in &popups {
+                    ui.table_next_column();
+                    if ui.small_button(format!("Remove##compare_idp{}", popup.state.id)) {
+                        removed_popup_id = Some(popup.state.id);
+                    }
+                }
+
+                let prices: Vec<Option<(u32, u32)>> = popups
+                    .iter()
+                    .map(|popup| {
+                        let item_ids = priced_item_ids(&popup.data.title, &popup.data.item_ids)?;
+                        let prices = self.cache.prices.retrieve(item_ids)?;
+                        let price = prices.get(&highest_sell_item_id(&prices)?)?.value()?;
+                        Some((price.lowest_sell, price.highest_buy))
+                    })
+                    .collect();
+                for (label, side) in [("Sell", 0), ("Buy", 1)] {
+                    let values: Vec<Option<u32>> = prices
+                        .iter()
+                        .map(|price| price.map(|price| if side == 0 { price.0 } else { price.1 }))
+                        .collect();
+                    if values.iter().all(Option::is_none) {
+                        continue;
+                    }
+                    ui.table_next_row();
+                    Self::render_compare_label(ui, label, &values);
+                    for value in values {
+                        ui.table_next_column();
+                        match value {
+                            Some(value) => {
+                                Self::render_price(ui, value, &mut self.cache, &rendering_params)
+                            }
+                            None => ui.text_disabled("-"),
+                        }
+                    }
+                }
+
+                let infobox_keys: IndexSet<&String> = popups
+                    .iter()
+                    .flat_map(|popup| popup.data.infobox.keys())
+                    .collect();
+                for key in infobox_keys {
+                    let values: Vec<Option<&String>> = popups
+                        .iter()
+                        .map(|popup| popup.data.infobox.get(key))
+                        .collect();
+                    ui.table_next_row();
+                    let differs = Self::render_compare_label(ui, key, &values);
+                    for value in values {
+                        ui.table_next_column();
+                        match value {
+                            Some(value) if differs => ui.text_colored(DIFFERENCE_COLOR, value),
+                            Some(value) => ui.text(value),
+                            None => ui.text_disabled("-"),
+                        }
+                    }
+                }
+
+                let section_names: IndexSet<&String> = popups
+                    .iter()
+                    .flat_map(|popup| popup.data.sections.iter())
+                    .filter(|(_, tokens)| !tokens.is_empty())
+                    .map(|(section_name, _)| section_name)
+                    .collect();
+                for section_name in section_names {
+                    let values: Vec<bool> = popups
+                        .iter()
+                        .map(|popup| {
+                            popup
+                                .data
+                                .sections
+                                .get(section_name)
+                                .is_some_and(|tokens| !tokens.is_empty())
+                        })
+                        .collect();
+                    ui.table_next_row();
+                    Self::render_compare_label(ui, &format!("{section_name} tab"), &values);
+                    for value in values {
+                        ui.table_next_column();
+                        if value {
+                            ui.text("Yes");
+                        } else {
+                            ui.text_disabled("No");
+                        }
+                    }
+                }
+                if let Some(removed_popup_id) = removed_popup_id {
+                    compared_popup_ids.retain(|id| *id != removed_popup_id);
+                }
+            });
+        self.ui.compare_opened = opened;
+        self.ui.compared_popup_ids = compared_popup_ids;
+    }
+
+    // Returns whether the values differ between the compared popups.
+    fn render_compare_label<T: PartialEq>(ui: &Ui, label: &str, values: &[T]) -> bool {
+        let differs = values.windows(2).any(|pair| pair[0] != pair[1]);
+        ui.table_next_column();
+        if differs {
+            ui.text_colored(DIFFERENCE_COLOR, label);
+        } else {
+            ui.text_disabled(label);
+        }
+        differs
+    }
+}
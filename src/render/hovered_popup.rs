@@ -50,6 +50,9 @@ impl Context {
                 UiAction::Close => {
                     self.ui.hovered_popup = None;
                 }
+                UiAction::OpenLightbox(lightbox) => {
+                    self.ui.lightbox = Some(lightbox.clone());
+                }
                 _ => {}
             }
         }
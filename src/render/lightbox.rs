@@ -0,0 +1,116 @@
+use crate::api::gw2_wiki::full_resolution_href;
+use crate::state::cache::caching_status::CachingStatus;
+use crate::state::cache::StoreInCache;
+use crate::state::context::Context;
+use crate::state::threads::image::save_image_thread;
+use log::debug;
+use nexus::imgui::{ChildWindow, Condition, Direction, MouseButton, Ui, Window};
+
+const LIGHTBOX_WINDOW_SIZE: [f32; 2] = [800.0, 600.0];
+const ZOOM_STEP: f32 = 1.25;
+const WHEEL_ZOOM_STEP: f32 = 1.1;
+const CAPTION_HEIGHT: f32 = 40.0;
+
+impl Context {
+    pub fn render_lightbox(&mut self, ui: &Ui) {
+        let Some(lightbox) = self.ui.lightbox.as_mut() else {
+            return;
+        };
+        debug!("[render_lightbox]");
+        let mut opened = true;
+        let cache = &mut self.cache;
+        Window::new("Image viewer##idp")
+            .size(LIGHTBOX_WINDOW_SIZE, Condition::FirstUseEver)
+            .opened(&mut opened)
+            .build(ui, || {
+                let image_count = lightbox.images.len();
+                if image_count > 1 {
+                    if ui.arrow_button("##lightbox_previous_idp", Direction::Left) {
+                        lightbox.previous();
+                    }
+                    ui.same_line();
+                    ui.text(format!("{} / {image_count}", lightbox.index + 1));
+                    ui.same_line();
+                    if ui.arrow_button("##lightbox_next_idp", Direction::Right) {
+                        lightbox.next();
+                    }
+                    ui.same_line();
+                }
+                if ui.small_button("-##lightbox_idp") {
+                    lightbox.zoom_by(1.0 / ZOOM_STEP);
+                }
+                ui.same_line();
+                ui.text(format!("{:.0}%", lightbox.zoom * 100.0));
+                ui.same_line();
+                if ui.small_button("+##lightbox_idp") {
+                    lightbox.zoom_by(ZOOM_STEP);
+                }
+                ui.same_line();
+                if ui.small_button("Reset##lightbox_idp") {
+                    lightbox.reset_view();
+                }
+                let href = full_resolution_href(&lightbox.current().href);
+                ui.same_line();
+                if ui.small_button("Save to disk##lightbox_idp") {
+                    save_image_thread(href.clone());
+                }
+
+                let avail = ui.content_region_avail();
+                let canvas_size = [avail[0], (avail[1] - CAPTION_HEIGHT).max(1.0)];
+                ChildWindow::new("lightbox_canvas##idp")
+                    .size(canvas_size)
+                    .border(true)
+                    .scrollable(false)
+                    .build(ui, || {
+                        let canvas_pos = ui.cursor_screen_pos();
+                        ui.invisible_button("##lightbox_canvas_idp", canvas_size);
+                        if ui.is_item_hovered() && ui.io().mouse_wheel != 0.0 {
+                            lightbox.zoom_by(WHEEL_ZOOM_STEP.powf(ui.io().mouse_wheel));
+                        }
+                        if ui.is_item_active() && ui.is_mouse_dragging(MouseButton::Left) {
+                            let delta = ui.io().mouse_delta;
+                            lightbox.pan = [lightbox.pan[0] + delta[0], lightbox.pan[1] + delta[1]];
+                        }
+                        if ui.is_item_clicked_with_button(MouseButton::Right) {
+                            lightbox.reset_view();
+                        }
+                        let Some(cached_data) = cache.textures.retrieve(href.clone()) else {
+                            return;
+                        };
+                        let texture = match (&cached_data.caching_status, cached_data.value()) {
+                            (CachingStatus::Failed, _) => {
+                                ui.set_cursor_pos([0.0, 0.0]);
+                                ui.text_disabled("Image unavailable.");
+                                return;
+                            }
+                            (_, Some(texture)) => texture,
+                            _ => {
+                                ui.set_cursor_pos([0.0, 0.0]);
+                                ui.text_disabled("Loading..");
+                                return;
+                            }
+                        };
+                        let (width, height) = (texture.width as f32, texture.height as f32);
+                        let fit_scale = (canvas_size[0] / width)
+                            .min(canvas_size[1] / height)
+                            .min(1.0);
+                        let scale = fit_scale * lightbox.zoom;
+                        let size = [width * scale, height * scale];
+                        let min = [
+                            canvas_pos[0] + (canvas_size[0] - size[0]) / 2.0 + lightbox.pan[0],
+                            canvas_pos[1] + (canvas_size[1] - size[1]) / 2.0 + lightbox.pan[1],
+                        ];
+                        ui.get_window_draw_list()
+                            .add_image(texture.id(), min, [min[0] + size[0], min[1] + size[1]])
+                            .build();
+                    });
+                match &lightbox.current().caption {
+                    Some(caption) => ui.text_wrapped(caption),
+                    None => ui.text_disabled("Scroll to zoom, drag to pan, right-click to reset."),
+                }
+            });
+        if !opened {
+            self.ui.lightbox = None;
+        }
+    }
+}
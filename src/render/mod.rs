@@ -3,13 +3,17 @@ use crate::state::context::Context;
 use log::debug;
 use nexus::imgui::Ui;
 
+mod bookmarks;
+mod compare;
 mod hovered_popup;
+mod lightbox;
 mod options;
 mod pinned_popup;
 pub mod popup_data;
 mod search;
 pub mod token;
 pub mod ui;
+mod watchlist;
 
 impl Context {
     pub fn render(&mut self, ui: &Ui) {
@@ -28,6 +32,10 @@ impl Context {
         self.render_pinned_popups(ui);
         self.render_search_prompt(ui);
         self.render_search_result(ui);
+        self.render_watchlist(ui);
+        self.render_compare(ui);
+        self.render_bookmarks(ui);
+        self.render_lightbox(ui);
     }
 
     fn render_progress_indicator(&mut self, ui: &Ui<'_>) {
@@ -1,6 +1,13 @@
-use crate::configuration::write_config;
+use crate::configuration::provider_params::ProviderKind;
+use crate::configuration::{read_config, write_config};
 use crate::state::context::Context;
 use nexus::imgui::Ui;
+use strum::IntoEnumIterator;
+
+const TAG_PREVIEW_DELAY_INPUT_WIDTH: f32 = 120.0;
+const MAX_TAG_PREVIEW_DELAY_MS: i32 = 5000;
+const LISTINGS_DEPTH_SLIDER_WIDTH: f32 = 120.0;
+const MAX_LISTINGS_DEPTH: i32 = 50;
 
 impl Context {
     pub fn render_advanced_options(&mut self, ui: &Ui) {
@@ -27,5 +34,88 @@ impl Context {
             "Collapse popups on title click##idp",
             &mut write_config().rendering_params.allow_popup_collapsing,
         );
+        ui.checkbox(
+            "Preview links on hover##idp",
+            &mut write_config().rendering_params.show_tag_previews,
+        );
+        if read_config().rendering_params.show_tag_previews {
+            let mut delay_ms =
+                i32::try_from(read_config().rendering_params.tag_preview_delay_ms).unwrap_or(0);
+            ui.set_next_item_width(TAG_PREVIEW_DELAY_INPUT_WIDTH);
+            if ui
+                .input_int("Preview delay (ms)##idp", &mut delay_ms)
+                .step(100)
+                .build()
+            {
+                write_config().rendering_params.tag_preview_delay_ms =
+                    delay_ms.clamp(0, MAX_TAG_PREVIEW_DELAY_MS) as u64;
+            }
+        }
+        render_provider_options(ui);
+    }
+}
+
+fn render_provider_options(ui: &Ui<'_>) {
+    let provider_params = read_config().provider_params.clone();
+    ui.spacing();
+    ui.text("Price source:");
+    let price_providers: Vec<ProviderKind> = ProviderKind::iter().collect();
+    if let Some(provider) = render_provider_combo(
+        ui,
+        "##price_provider_idp",
+        &price_providers,
+        provider_params.price_providers.first(),
+    ) {
+        write_config()
+            .provider_params
+            .set_primary_price_provider(provider);
+    }
+    let mut listings_depth =
+        i32::try_from(read_config().rendering_params.listings_depth).unwrap_or(1);
+    ui.set_next_item_width(LISTINGS_DEPTH_SLIDER_WIDTH);
+    if ui.slider(
+        "Listings shown per side##idp",
+        1,
+        MAX_LISTINGS_DEPTH,
+        &mut listings_depth,
+    ) {
+        write_config().rendering_params.listings_depth =
+            listings_depth.clamp(1, MAX_LISTINGS_DEPTH) as usize;
+    }
+
+    ui.text("Item name source:");
+    let item_name_providers: Vec<ProviderKind> = ProviderKind::iter()
+        .filter(|provider| *provider != ProviderKind::OfficialApi)
+        .collect();
+    if let Some(provider) = render_provider_combo(
+        ui,
+        "##item_name_provider_idp",
+        &item_name_providers,
+        provider_params.item_name_providers.first(),
+    ) {
+        write_config()
+            .provider_params
+            .set_primary_item_name_provider(provider);
+    }
+    ui.text_disabled(format!(
+        "Other sources are used as a fallback. The local file is read from \"{}\" in the addon directory.",
+        provider_params.snapshot_file_name
+    ));
+}
+
+fn render_provider_combo(
+    ui: &Ui<'_>,
+    label: &str,
+    providers: &[ProviderKind],
+    primary: Option<&ProviderKind>,
+) -> Option<ProviderKind> {
+    let mut current_item = primary
+        .and_then(|primary| providers.iter().position(|v| v == primary))
+        .unwrap_or(0);
+    if ui.combo(label, &mut current_item, providers, |provider| {
+        format!("{}", provider).into()
+    }) {
+        return providers.get(current_item).copied();
     }
+    None
 }
@@ -1,9 +1,11 @@
+use crate::configuration::price_history_source::PriceHistorySource;
 use crate::configuration::{read_config, write_config};
 use crate::state::context::Context;
 use crate::utils::time::HourAndMinute;
 use log::debug;
 use nexus::imgui::Ui;
 use std::time::Duration;
+use strum::IntoEnumIterator;
 
 const MAX_REFRESH_HOURS: i32 = 10000;
 const MAX_REFRESH_MINUTES: i32 = 59;
@@ -16,6 +18,7 @@ impl Context {
         self.render_max_cached_popup_data_elements(ui);
         self.render_max_popup_data_expiration(ui);
         self.render_price_expiration(ui);
+        render_price_history_source(ui);
         self.render_max_texture_cache_expiration(ui);
         ui.new_line();
         self.render_cache_used(ui);
@@ -111,3 +114,24 @@ impl Context {
         }
     }
 }
+
+fn render_price_history_source(ui: &Ui<'_>) {
+    let sources: Vec<PriceHistorySource> = PriceHistorySource::iter().collect();
+    let mut current_item = sources
+        .iter()
+        .position(|v| *v == read_config().price_history_source)
+        .unwrap();
+
+    ui.spacing();
+    ui.text("Price history:");
+    ui.combo(
+        "##phs_idp",
+        &mut current_item,
+        &sources,
+        |selected_source| format!("{}", selected_source).into(),
+    );
+    write_config().price_history_source = sources
+        .get(current_item)
+        .expect("Should have expected price history source.")
+        .clone();
+}
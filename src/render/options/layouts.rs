@@ -0,0 +1,56 @@
+use crate::configuration::{read_config, write_config};
+use crate::service::layout::{capture_layout, restore_layout};
+use crate::state::context::Context;
+use log::debug;
+use nexus::imgui::{TableFlags, Ui};
+
+impl Context {
+    pub fn render_layouts_options(&mut self, ui: &Ui) {
+        debug!("[render_layouts_options] Started.");
+        ui.text("Save pinned popups as a layout:");
+        ui.input_text("##layout_name_idp", &mut self.ui.layout_name_input)
+            .build();
+        ui.same_line();
+        let layout_name = self.ui.layout_name_input.trim().to_string();
+        if layout_name.is_empty() || self.ui.pinned_popups.is_empty() {
+            ui.text_disabled("Save");
+        } else if ui.button("Save##layout_idp") {
+            let layout = capture_layout(&self.ui.pinned_popups);
+            write_config().layouts.saved.insert(layout_name, layout);
+            self.ui.layout_name_input.clear();
+        }
+
+        let saved_layouts = read_config().layouts.saved.clone();
+        if saved_layouts.is_empty() {
+            ui.text_disabled("No saved layouts.");
+            return;
+        }
+        ui.spacing();
+        let Some(_t) = ui.begin_table_with_flags(
+            "layouts##idp",
+            4,
+            TableFlags::NO_SAVED_SETTINGS | TableFlags::ROW_BG,
+        ) else {
+            return;
+        };
+        for header in ["Layout", "Popups", "", ""] {
+            ui.table_setup_column(header);
+        }
+        ui.table_headers_row();
+        for (name, layout) in &saved_layouts {
+            ui.table_next_row();
+            ui.table_next_column();
+            ui.text(name);
+            ui.table_next_column();
+            ui.text(layout.len().to_string());
+            ui.table_next_column();
+            if ui.small_button(format!("Load##{name}_layout_idp")) {
+                restore_layout(self, layout);
+            }
+            ui.table_next_column();
+            if ui.small_button(format!("Delete##{name}_layout_idp")) {
+                write_config().layouts.saved.remove(name);
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ mod advanced;
 mod cache;
 mod general;
 mod help;
+mod layouts;
 pub mod r#macro;
 mod style;
 
@@ -27,6 +28,9 @@ impl Context {
             if let Some(_token) = ui.tab_item("Cache") {
                 self.render_cache_options(ui);
             }
+            if let Some(_token) = ui.tab_item("Layouts") {
+                self.render_layouts_options(ui);
+            }
             if let Some(_token) = ui.tab_item("Advanced") {
                 self.render_advanced_options(ui);
             }
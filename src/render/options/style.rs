@@ -1,10 +1,14 @@
+use crate::configuration::popup::tab_rules::{ArticleType, TabRule, TabRuleAction};
 use crate::configuration::{read_config, write_config};
 use crate::render::ui::UiExtended;
 use crate::state::context::Context;
 use crate::state::threads::font::load_fonts;
-use nexus::imgui::{Key, TreeNodeFlags, Ui};
+use nexus::imgui::{Direction, TreeNodeFlags, Ui};
+use strum::IntoEnumIterator;
 
 const ERROR_COLOR: [f32; 4] = [0.4, 0.4, 0.4, 1.0];
+const TAB_RULE_INPUT_WIDTH: f32 = 200.0;
+const TAB_RULE_COMBO_WIDTH: f32 = 130.0;
 
 impl Context {
     pub fn render_style_options(&mut self, ui: &Ui) {
@@ -95,49 +99,117 @@ impl Context {
                 &mut write_config().rendering_params.show_tag_bar,
             );
 
-            let blacklisted_tabs = &mut write_config().rendering_params.blacklisted_tabs;
-            ui.spacing();
-            ui.text("Blacklisted tabs:");
-            if blacklisted_tabs.is_empty() {
-                ui.text_disabled("No tabs");
-            } else {
-                let mut to_remove_vec = Vec::new();
-                if let Some(_t) = ui.begin_table("blacklisted_tabs#idp", 3) {
-                    ui.table_next_row();
-                    for (i, tab_title) in blacklisted_tabs.iter().enumerate() {
-                        ui.table_next_column();
-                        ui.text_colored(ERROR_COLOR, "[X]");
-                        ui.same_line_with_pos(-10f32);
-                        if ui.invisible_button(
-                            format!("-##blacklisted_tabs{}", tab_title),
-                            [30f32, 30f32],
-                        ) {
-                            to_remove_vec.push(i);
-                        }
-                        ui.same_line_with_pos(24f32);
-                        ui.text(tab_title);
-                    }
+            self.render_tab_rules(ui);
+        }
+    }
+
+    fn render_tab_rules(&mut self, ui: &Ui<'_>) {
+        ui.spacing();
+        ui.text("Tab rules:");
+        ui.same_line();
+        ui.text_disabled("(the first matching rule applies, '*' matches any text)");
+        ui.checkbox(
+            "Remember selected tab per article type##idp",
+            &mut write_config()
+                .rendering_params
+                .tab_rules
+                .remember_selected_tab,
+        );
+        let mut rules = read_config().rendering_params.tab_rules.rules.clone();
+        let mut changed = false;
+        if rules.is_empty() {
+            ui.text_disabled("No rules");
+        } else if let Some(_t) = ui.begin_table("tab_rules#idp", 4) {
+            let mut moved_rule = None;
+            let mut removed_rule = None;
+            for (i, rule) in rules.iter().enumerate() {
+                ui.table_next_row();
+                ui.table_next_column();
+                if ui.arrow_button(format!("##tab_rule_up{i}"), Direction::Up) && i > 0 {
+                    moved_rule = Some((i, i - 1));
+                }
+                ui.same_line();
+                if ui.arrow_button(format!("##tab_rule_down{i}"), Direction::Down)
+                    && i + 1 < rules.len()
+                {
+                    moved_rule = Some((i, i + 1));
                 }
-                for blacklisted_tab_index in to_remove_vec {
-                    blacklisted_tabs.remove(blacklisted_tab_index);
+                ui.same_line();
+                ui.text(&rule.pattern);
+                ui.table_next_column();
+                match rule.action {
+                    TabRuleAction::Pin => ui.text(format!("{}", rule.action)),
+                    TabRuleAction::Hide => ui.text_colored(ERROR_COLOR, format!("{}", rule.action)),
                 }
+                ui.table_next_column();
+                ui.text_disabled(format!("{}", rule.article_type));
+                ui.table_next_column();
+                if ui.small_button(format!("Remove##tab_rule{i}")) {
+                    removed_rule = Some(i);
+                }
+            }
+            if let Some((from, to)) = moved_rule {
+                rules.swap(from, to);
+                changed = true;
             }
-            ui.spacing();
-            ui.text("Add to blacklist:");
-            ui.same_line();
-            ui.text_disabled("(press enter to confirm)");
-            ui.input_text(
-                "##add_to_blacklist_idp",
-                &mut self.ui.tab_to_blacklist_input,
-            )
+            if let Some(i) = removed_rule {
+                rules.remove(i);
+                changed = true;
+            }
+        }
+
+        ui.spacing();
+        ui.text("Add rule:");
+        let new_rule = &mut self.ui.new_tab_rule;
+        ui.set_next_item_width(TAB_RULE_INPUT_WIDTH);
+        ui.input_text("##tab_rule_pattern_idp", &mut new_rule.pattern)
+            .hint("Tab name or pattern")
             .build();
-            if ui.is_key_released(Key::Enter) && ui.is_item_focused() {
-                let tab = self.ui.tab_to_blacklist_input.to_lowercase();
-                if !blacklisted_tabs.contains(&tab) && !self.ui.tab_to_blacklist_input.is_empty() {
-                    blacklisted_tabs.push(self.ui.tab_to_blacklist_input.to_lowercase());
-                    self.ui.tab_to_blacklist_input = "".to_string();
-                }
+        ui.same_line();
+        let actions: Vec<TabRuleAction> = TabRuleAction::iter().collect();
+        let mut current_action = actions
+            .iter()
+            .position(|action| *action == new_rule.action)
+            .unwrap_or(0);
+        ui.set_next_item_width(TAB_RULE_COMBO_WIDTH);
+        if ui.combo(
+            "##tab_rule_action_idp",
+            &mut current_action,
+            &actions,
+            |action| format!("{}", action).into(),
+        ) {
+            new_rule.action = actions[current_action].clone();
+        }
+        ui.same_line();
+        let article_types: Vec<ArticleType> = ArticleType::iter().collect();
+        let mut current_type = article_types
+            .iter()
+            .position(|article_type| *article_type == new_rule.article_type)
+            .unwrap_or(0);
+        ui.set_next_item_width(TAB_RULE_COMBO_WIDTH);
+        if ui.combo(
+            "##tab_rule_type_idp",
+            &mut current_type,
+            &article_types,
+            |article_type| format!("{}", article_type).into(),
+        ) {
+            new_rule.article_type = article_types[current_type].clone();
+        }
+        ui.same_line();
+        let pattern = new_rule.pattern.trim().to_string();
+        if ui.button("Add##tab_rule_idp") && !pattern.is_empty() {
+            let rule = TabRule {
+                pattern,
+                ..new_rule.clone()
+            };
+            if !rules.contains(&rule) {
+                rules.push(rule);
+                changed = true;
             }
+            new_rule.pattern.clear();
+        }
+        if changed {
+            write_config().rendering_params.tab_rules.rules = rules;
         }
     }
 
@@ -2,6 +2,7 @@ use crate::render::ui::{UiAction, CLOSE_BUTTON_MARGIN_OUTER_X, CLOSE_BUTTON_SIZE
 use crate::state::cache::Cache;
 use crate::state::context::Context;
 use crate::state::font::Font;
+use crate::state::popup::navigation::NavigationEntry;
 use crate::state::popup::{dimensions::Dimensions, popup_state::PopupState, Popup};
 use crate::state::threads::link::open_link_thread;
 use crate::state::threads::popup::refresh_popup_thread;
@@ -95,7 +96,45 @@ impl Context {
                     }
                 }
                 UiAction::Open(ui_link) => {
-                    open_link_thread(ui_link.href.clone(), ui_link.title.clone())
+                    match vec
+                        .iter_mut()
+                        .find(|popup| popup.state.id == ui_link.popup_id)
+                    {
+                        // links followed from a pinned popup open in place
+                        Some(popup) => {
+                            popup.state.navigation = popup
+                                .state
+                                .navigation
+                                .followed_from(popup.navigation_entry());
+                            let target = NavigationEntry {
+                                href: ui_link.href.clone(),
+                                title: ui_link.title.clone(),
+                                item_ids: None,
+                            };
+                            Self::navigate(popup, target, &mut self.cache);
+                        }
+                        None => open_link_thread(ui_link.href.clone(), ui_link.title.clone()),
+                    }
+                }
+                UiAction::Compare(popup_id) => {
+                    let compared_popup_ids = &mut self.ui.compared_popup_ids;
+                    if !compared_popup_ids.contains(popup_id) {
+                        compared_popup_ids.push(*popup_id);
+                    }
+                    let other_popup_ids: Vec<u64> = vec
+                        .iter()
+                        .map(|popup| popup.state.id)
+                        .filter(|id| id != popup_id)
+                        .collect();
+                    if let [other_popup_id] = other_popup_ids[..] {
+                        if !compared_popup_ids.contains(&other_popup_id) {
+                            compared_popup_ids.push(other_popup_id);
+                        }
+                    }
+                    self.ui.compare_opened = true;
+                }
+                UiAction::OpenLightbox(lightbox) => {
+                    self.ui.lightbox = Some(lightbox.clone());
                 }
                 _ => {}
             }
@@ -0,0 +1,144 @@
+use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::render::popup_data::price::LOSS_COLOR;
+use crate::state::cache::Cache;
+use crate::state::cache::StoreInCache;
+use crate::state::context::Context;
+use crate::state::popup::container_contents::{ContainerContents, ContentsKind};
+use nexus::imgui::{TableFlags, TreeNodeFlags, Ui};
+
+const GAIN_COLOR: [f32; 4] = [0.2, 0.8, 0.2, 1.0];
+
+impl Context {
+    pub fn render_contents(
+        ui: &Ui,
+        popup_id: u64,
+        contents: &Option<ContainerContents>,
+        item_ids: &Option<Vec<u32>>,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        let Some(contents) = contents else { return };
+        if !ui.collapsing_header(
+            format!("{}: expected value##idp{popup_id}", contents.kind),
+            TreeNodeFlags::SPAN_AVAIL_WIDTH,
+        ) {
+            return;
+        }
+        let outcome_item_ids: Vec<Option<Vec<u32>>> = match cache.item_names.retrieve(()) {
+            Some(item_names) => contents
+                .outcomes
+                .iter()
+                .map(|outcome| item_names.get(&outcome.name).cloned())
+                .collect(),
+            None => vec![None; contents.outcomes.len()],
+        };
+        let all_item_ids: Vec<u32> = outcome_item_ids
+            .iter()
+            .flatten()
+            .flatten()
+            .chain(item_ids.iter().flatten())
+            .copied()
+            .collect();
+        let Some(prices) = cache.prices.retrieve(all_item_ids) else {
+            return;
+        };
+        let unit_price = |ids: &Option<Vec<u32>>| {
+            ids.iter()
+                .flatten()
+                .filter_map(|item_id| prices.get(item_id).and_then(|price| price.value()))
+                .map(|price| price.lowest_sell)
+                .max()
+                .filter(|lowest_sell| *lowest_sell > 0)
+        };
+
+        let mut expected_value = 0.0;
+        let mut unpriced_outcomes = 0;
+        let mut outcomes_without_chance = 0;
+        if let Some(_t) = ui.begin_table_with_flags(
+            format!("contents##idp{popup_id}"),
+            4,
+            TableFlags::NO_SAVED_SETTINGS | TableFlags::ROW_BG | TableFlags::SIZING_FIXED_FIT,
+        ) {
+            for header in ["Item", "Quantity", "Chance", "Value"] {
+                ui.table_setup_column(header);
+            }
+            ui.table_headers_row();
+            for (outcome, ids) in contents.outcomes.iter().zip(&outcome_item_ids) {
+                ui.table_next_row();
+                ui.table_next_column();
+                ui.text(&outcome.name);
+                ui.table_next_column();
+                if outcome.min_quantity == outcome.max_quantity {
+                    ui.text(format!("{}", outcome.min_quantity));
+                } else {
+                    ui.text(format!("{}-{}", outcome.min_quantity, outcome.max_quantity));
+                }
+                ui.table_next_column();
+                match outcome.probability {
+                    Some(probability) => ui.text(format!("{:.2}%", probability * 100.0)),
+                    None => ui.text_disabled("-"),
+                }
+                ui.table_next_column();
+                match (unit_price(ids), outcome.expected_quantity()) {
+                    (Some(unit_price), Some(expected_quantity)) => {
+                        let value = unit_price as f32 * expected_quantity;
+                        expected_value += value;
+                        Self::render_price(ui, value.round() as u32, cache, rendering_params);
+                    }
+                    (None, _) => {
+                        unpriced_outcomes += 1;
+                        ui.text_disabled("no price");
+                    }
+                    (Some(_), None) => {
+                        outcomes_without_chance += 1;
+                        ui.text_disabled("no chance");
+                    }
+                }
+            }
+        }
+
+        // without any chance the outcomes can't be weighted, so no verdict is given
+        if !contents.has_probabilities() {
+            ui.text_disabled(" Insufficient data: the wiki lists no chances for these outcomes");
+            return;
+        }
+        let expected_value = expected_value.round() as u32;
+        let per_action = match contents.kind {
+            ContentsKind::Contents => "Expected value per open: ",
+            ContentsKind::Salvage => "Expected value per salvage: ",
+        };
+        ui.text_disabled(per_action);
+        ui.same_line();
+        Self::render_price(ui, expected_value, cache, rendering_params);
+        let mut left_out = vec![];
+        if unpriced_outcomes > 0 {
+            left_out.push(format!("{unpriced_outcomes} without a price"));
+        }
+        if outcomes_without_chance > 0 {
+            left_out.push(format!("{outcomes_without_chance} without a chance"));
+        }
+        if !left_out.is_empty() {
+            ui.same_line();
+            ui.text_disabled(format!(" (not counted: {})", left_out.join(", ")));
+        }
+
+        let Some(sell_price) = unit_price(item_ids) else {
+            return;
+        };
+        ui.text_disabled(" Sells for: ");
+        ui.same_line();
+        Self::render_price(ui, sell_price, cache, rendering_params);
+        let (color, verdict, difference) = match (contents.kind.clone(), expected_value) {
+            (ContentsKind::Contents, value) if value > sell_price => {
+                (GAIN_COLOR, "Worth opening by ", value - sell_price)
+            }
+            (ContentsKind::Salvage, value) if value > sell_price => {
+                (GAIN_COLOR, "Worth salvaging by ", value - sell_price)
+            }
+            (_, value) => (LOSS_COLOR, "Better sold by ", sell_price - value),
+        };
+        ui.text_colored(color, verdict);
+        ui.same_line();
+        Self::render_price(ui, difference, cache, rendering_params);
+    }
+}
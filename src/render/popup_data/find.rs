@@ -0,0 +1,115 @@
+use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::state::context::Context;
+use crate::state::popup::find::{find_matches, FindState};
+use crate::state::popup::Popup;
+use nexus::imgui::{Direction, Key, Ui, WindowFocusedFlags};
+
+const FIND_INPUT_WIDTH: f32 = 200.0;
+
+impl Context {
+    // Returns the tab of every match, see find_matches.
+    pub fn render_find_bar(
+        ui: &Ui,
+        popup: &mut Popup,
+        rendering_params: &RenderingParams,
+    ) -> Vec<String> {
+        let focused = ui.is_window_focused_with_flags(WindowFocusedFlags::CHILD_WINDOWS);
+        if focused && ui.io().key_ctrl && ui.is_key_pressed(Key::F) {
+            popup
+                .state
+                .find
+                .get_or_insert_with(FindState::default)
+                .focus_input = true;
+        }
+        let Some(find_state) = popup.state.find.as_mut() else {
+            return vec![];
+        };
+        if focused && ui.is_key_pressed(Key::Escape) {
+            popup.state.find = None;
+            return vec![];
+        }
+        if find_state.focus_input {
+            ui.set_keyboard_focus_here();
+            find_state.focus_input = false;
+        }
+        ui.set_next_item_width(FIND_INPUT_WIDTH);
+        let previous_query = find_state.query.clone();
+        let submitted = ui
+            .input_text(
+                format!("##find_idp{}", popup.state.id),
+                &mut find_state.query,
+            )
+            .hint("Find")
+            .enter_returns_true(true)
+            .build();
+        if find_state.query != previous_query {
+            find_state.current_match = 0;
+            find_state.scroll_to_current = true;
+        }
+        let matches = find_matches(
+            &popup.data,
+            &find_state.query,
+            rendering_params,
+            &popup.state.tables,
+        );
+
+        let mut step = None;
+        if submitted {
+            find_state.focus_input = true;
+            step = Some(!ui.io().key_shift);
+        }
+        ui.same_line();
+        if ui.arrow_button(
+            format!("##find_previous_idp{}", popup.state.id),
+            Direction::Up,
+        ) {
+            step = Some(false);
+        }
+        ui.same_line();
+        if ui.arrow_button(
+            format!("##find_next_idp{}", popup.state.id),
+            Direction::Down,
+        ) {
+            step = Some(true);
+        }
+        ui.same_line();
+        if matches.is_empty() {
+            if !find_state.query.is_empty() {
+                ui.text_disabled("No matches");
+                ui.same_line();
+            }
+        } else {
+            find_state.current_match = find_state.current_match.min(matches.len() - 1);
+            if let Some(forward) = step {
+                find_state.current_match = if forward {
+                    (find_state.current_match + 1) % matches.len()
+                } else {
+                    (find_state.current_match + matches.len() - 1) % matches.len()
+                };
+                find_state.scroll_to_current = true;
+            }
+            ui.text(format!(
+                "{}/{}",
+                find_state.current_match + 1,
+                matches.len()
+            ));
+            ui.same_line();
+        }
+        if ui.small_button(format!("x##find_close_idp{}", popup.state.id)) {
+            popup.state.find = None;
+            ui.new_line();
+            return vec![];
+        }
+        ui.new_line();
+
+        if find_state.scroll_to_current {
+            if let Some(tab) = matches.get(find_state.current_match) {
+                if popup.state.selected_tab.as_ref() != Some(tab) {
+                    popup.state.selected_tab = Some(tab.clone());
+                    popup.state.restore_selected_tab = true;
+                }
+            }
+        }
+        matches
+    }
+}
@@ -0,0 +1,71 @@
+use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::render::ui::UiAction;
+use crate::service::popup::copy_chat_code;
+use crate::state::cache::Cache;
+use crate::state::context::Context;
+use crate::state::popup::Popup;
+use nexus::imgui::Ui;
+
+impl Context {
+    pub fn render_location_tab(
+        ui: &Ui<'_>,
+        pinned_popup_index: Option<usize>,
+        popup: &mut Popup,
+        ui_actions: &mut Vec<UiAction>,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        let Some(location) = &popup.data.location else {
+            return;
+        };
+        let token = Self::popup_tab_item(ui, "Location", &mut popup.state);
+        if ui.is_item_hovered()
+            && pinned_popup_index.is_none()
+            && rendering_params.auto_pin_on_tab_hover
+        {
+            Self::pin_popup(ui, &mut popup.state, ui_actions);
+        }
+        if token.is_none() {
+            return;
+        }
+        ui.spacing();
+        for (label, value) in [
+            ("Map", &location.map_name),
+            ("Region", &location.region),
+            ("Area", &location.area),
+        ] {
+            if let Some(value) = value {
+                ui.text_disabled(format!("{label}:"));
+                ui.same_line();
+                ui.text(value);
+            }
+        }
+        for (category, tags) in &location.nearby {
+            ui.spacing();
+            ui.text_disabled(format!("Nearby {}:", category.to_lowercase()));
+            for tag_params in tags {
+                ui.same_line();
+                Self::render_tag(
+                    ui,
+                    tag_params,
+                    &mut popup.state.pinned,
+                    popup.state.id,
+                    ui_actions,
+                    0,
+                    cache,
+                    rendering_params,
+                    &mut None,
+                );
+            }
+        }
+        if let Some(chat_code) = &location.chat_code {
+            ui.spacing();
+            if ui.button(format!("Copy waypoint code##idp{}", popup.state.id)) {
+                copy_chat_code(chat_code);
+            }
+            ui.same_line();
+            ui.text_disabled(chat_code);
+        }
+        ui.new_line();
+    }
+}
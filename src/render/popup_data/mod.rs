@@ -1,22 +1,35 @@
 use crate::api::gw2_wiki::href_to_wiki_url;
 use crate::configuration::popup::rendering_params::RenderingParams;
-use crate::configuration::read_config;
+use crate::configuration::popup::tab_rules::ArticleType;
+use crate::configuration::{read_config, write_config};
+use crate::render::popup_data::price::{highest_sell_item_id, priced_item_ids};
 use crate::render::ui::{UiAction, UiExtended, UiLink};
+use crate::service::export::{copy_popup_content, ExportFormat};
 use crate::service::popup::{close_all_popups, copy_popup_title, process_text};
 use crate::state::cache::caching_status::CachingStatus;
+use crate::state::cache::texture::capped_texture_href;
 use crate::state::cache::{Cache, StoreInCache};
 use crate::state::context::Context;
 use crate::state::font::Font;
+use crate::state::lightbox::Lightbox;
+use crate::state::popup::find::{TextHighlight, GENERAL_TAB};
 use crate::state::popup::popup_state::PopupState;
 use crate::state::popup::token::Token;
 use crate::state::popup::Popup;
 use log::{debug, error};
 use nexus::imgui::MenuItem;
-use nexus::imgui::{sys, TabBarFlags};
+use nexus::imgui::{sys, TabBarFlags, TabItemFlags, TabItemToken};
 use nexus::imgui::{ChildWindow, MouseButton, Ui};
 use std::{f32, ptr};
 
+mod contents;
+mod find;
+mod location;
+mod navigation;
+mod notes;
 pub mod price;
+mod price_history;
+mod variants;
 
 const NON_CHILD_WINDOW_TEXT_WRAP_LIMIT: usize = 25;
 const ADDITIONAL_SCROLLABLE_MARGIN_RIGHT: f32 = 45.0;
@@ -79,11 +92,15 @@ impl Context {
         bold_font: &Option<Font>,
         rendering_params: &RenderingParams,
     ) {
+        Self::render_navigation_buttons(ui, popup, cache);
         let dimensions = match &popup.data.item_icon {
             Some(Token::Image(href, dimensions)) => Self::render_image(ui, href, dimensions, cache),
             _ => None,
         };
         ui.same_line();
+        let icon_height = dimensions.as_ref().map(|dimensions| dimensions.height);
+        Self::render_bookmark_toggle(ui, popup, icon_height);
+        Self::render_note_indicator(ui, popup, cache, icon_height);
         let mut _token = None;
         let processed_title = process_text(
             Self::crop_title_to_ui_width(ui, &popup.data.title, rendering_params.max_content_width)
@@ -133,12 +150,15 @@ impl Context {
             Self::render_tag_bar(ui, popup, ui_actions, rendering_params);
         }
         if popup.data.is_not_empty() {
+            let find_matches = Self::render_find_bar(ui, popup, rendering_params);
+            let article_type = ArticleType::detect(&popup.data);
+            Self::restore_remembered_tab(&mut popup.state, &article_type, rendering_params);
             if let Some(_token) = ui.tab_bar_with_flags(
                 format!("tabs##idp{}", popup.state.id),
                 TabBarFlags::FITTING_POLICY_RESIZE_DOWN,
             ) {
                 Self::render_tab(
-                    "General",
+                    GENERAL_TAB,
                     ui,
                     pinned_popup_index,
                     ui_actions,
@@ -150,15 +170,22 @@ impl Context {
                     &mut popup.state,
                     rendering_params,
                     true,
+                    &find_matches,
                 );
 
-                for (section_name, tokens) in &popup.data.sections {
-                    if rendering_params
-                        .blacklisted_tabs
-                        .contains(&section_name.to_lowercase())
-                    {
-                        continue;
-                    }
+                Self::render_location_tab(
+                    ui,
+                    pinned_popup_index,
+                    popup,
+                    ui_actions,
+                    cache,
+                    rendering_params,
+                );
+
+                for (section_name, tokens) in rendering_params
+                    .tab_rules
+                    .ordered_sections(&popup.data.sections, &article_type)
+                {
                     Self::render_tab(
                         section_name,
                         ui,
@@ -168,10 +195,11 @@ impl Context {
                         bold_font,
                         &None,
                         tokens,
-                        !rendering_params.blacklisted_tabs.contains(section_name),
+                        true,
                         &mut popup.state,
                         rendering_params,
                         false,
+                        &find_matches,
                     );
                 }
 
@@ -183,6 +211,16 @@ impl Context {
                     cache,
                     rendering_params,
                 );
+                Self::render_notes_tab(
+                    ui,
+                    pinned_popup_index,
+                    popup,
+                    ui_actions,
+                    cache,
+                    rendering_params,
+                );
+                popup.state.restore_selected_tab = false;
+                Self::remember_selected_tab(&mut popup.state, &article_type, rendering_params);
             }
         }
         Self::render_ribbon(
@@ -197,6 +235,7 @@ impl Context {
         );
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_tab(
         tab_name: &str,
         ui: &Ui<'_>,
@@ -210,10 +249,11 @@ impl Context {
         popup_state: &mut PopupState,
         rendering_params: &RenderingParams,
         general_tab: bool,
+        find_matches: &[String],
     ) {
         debug!("[render_tab] render {tab_name} tab");
         if should_render && (!tokens.is_empty() || (general_tab && item_ids.is_some())) {
-            let token = ui.tab_item(format!("{tab_name}##idp{}", popup_state.id));
+            let token = Self::popup_tab_item(ui, tab_name, popup_state);
             if ui.is_item_hovered()
                 && pinned_popup_index.is_none()
                 && rendering_params.auto_pin_on_tab_hover
@@ -221,6 +261,10 @@ impl Context {
                 Self::pin_popup(ui, popup_state, ui_actions);
             }
             if token.is_some() {
+                let mut highlight = popup_state.find.as_ref().and_then(|find_state| {
+                    let first_match = find_matches.iter().position(|tab| tab == tab_name)?;
+                    TextHighlight::new(find_state, first_match)
+                });
                 let mut render_func = || {
                     Self::render_tokens(
                         ui,
@@ -233,6 +277,8 @@ impl Context {
                         bold_font,
                         rendering_params,
                         true,
+                        &mut popup_state.tables,
+                        &mut highlight,
                     );
                 };
                 let contains_table = tokens.iter().any(|t| matches!(t, Token::Table(..)));
@@ -256,9 +302,11 @@ impl Context {
                         .border(true)
                         .scroll_bar(true)
                         .build(ui, render_func);
+                    Self::finish_highlight(popup_state, &highlight);
                     return;
                 }
                 render_func();
+                Self::finish_highlight(popup_state, &highlight);
                 if !tokens.is_empty() {
                     ui.new_line();
                 }
@@ -266,6 +314,77 @@ impl Context {
         }
     }
 
+    fn restore_remembered_tab(
+        popup_state: &mut PopupState,
+        article_type: &ArticleType,
+        rendering_params: &RenderingParams,
+    ) {
+        if popup_state.selected_tab.is_some() || !rendering_params.tab_rules.remember_selected_tab {
+            return;
+        }
+        if let Some(tab) = rendering_params.tab_rules.selected_tabs.get(article_type) {
+            popup_state.selected_tab = Some(tab.clone());
+            popup_state.restore_selected_tab = true;
+        }
+    }
+
+    // Only tabs picked by the user are remembered, not the ones imgui falls back to.
+    fn remember_selected_tab(
+        popup_state: &mut PopupState,
+        article_type: &ArticleType,
+        rendering_params: &RenderingParams,
+    ) {
+        let Some(clicked_tab) = popup_state.clicked_tab.clone() else {
+            return;
+        };
+        if popup_state.selected_tab.as_ref() != Some(&clicked_tab) {
+            return;
+        }
+        popup_state.clicked_tab = None;
+        if rendering_params.tab_rules.remember_selected_tab {
+            write_config()
+                .rendering_params
+                .tab_rules
+                .selected_tabs
+                .insert(article_type.clone(), clicked_tab);
+        }
+    }
+
+    fn finish_highlight(popup_state: &mut PopupState, highlight: &Option<TextHighlight>) {
+        if highlight
+            .as_ref()
+            .is_some_and(|highlight| !highlight.scroll_to_current)
+        {
+            if let Some(find_state) = popup_state.find.as_mut() {
+                find_state.scroll_to_current = false;
+            }
+        }
+    }
+
+    pub fn popup_tab_item<'ui>(
+        ui: &'ui Ui,
+        tab_name: &str,
+        popup_state: &mut PopupState,
+    ) -> Option<TabItemToken<'ui>> {
+        let flags = if popup_state.restore_selected_tab
+            && popup_state.selected_tab.as_deref() == Some(tab_name)
+        {
+            popup_state.restore_selected_tab = false;
+            TabItemFlags::SET_SELECTED
+        } else {
+            TabItemFlags::empty()
+        };
+        let token =
+            ui.tab_item_with_flags(format!("{tab_name}##idp{}", popup_state.id), None, flags);
+        if ui.is_item_clicked() {
+            popup_state.clicked_tab = Some(tab_name.to_string());
+        }
+        if token.is_some() && !popup_state.restore_selected_tab {
+            popup_state.selected_tab = Some(tab_name.to_string());
+        }
+        token
+    }
+
     fn render_images_tab(
         ui: &Ui<'_>,
         pinned_popup_index: Option<usize>,
@@ -277,7 +396,7 @@ impl Context {
         if !rendering_params.show_images_tab || popup.data.images.is_empty() {
             return;
         }
-        let token = ui.tab_item(format!("Images##idp{}", popup.state.id));
+        let token = Self::popup_tab_item(ui, "Images", &mut popup.state);
         if ui.is_item_hovered()
             && pinned_popup_index.is_none()
             && rendering_params.auto_pin_on_tab_hover
@@ -289,19 +408,23 @@ impl Context {
                 for token in &popup.data.images {
                     match token {
                         Token::Image(href, _) => {
-                            let cached_data_opt = cache.textures.retrieve(href.to_string());
+                            let max_width = rendering_params.max_content_width;
+                            let ui_scale = ui.ui_scale();
+                            let texture_href = capped_texture_href(href, max_width * ui_scale);
+                            let cached_data_opt = cache.textures.retrieve(texture_href);
                             if let Some(cached_data) = cached_data_opt {
                                 if let CachingStatus::Cached = cached_data.caching_status {
                                     if let Some(texture) = cached_data.value() {
+                                        // textures are sized in pixels, the layout in ui units
+                                        let scale =
+                                            (max_width / texture.width as f32).min(1.0 / ui_scale);
+                                        let width = texture.width as f32 * scale;
+                                        let height = texture.height as f32 * scale;
                                         let window_width = ui.window_size()[0];
-                                        let start_offset =
-                                            window_width / 2.0 - texture.width as f32 / 2.0;
+                                        let start_offset = window_width / 2.0 - width / 2.0;
                                         ui.set_cursor_pos([start_offset, ui.cursor_pos()[1]]);
-                                        ui.invisible_button(
-                                            href,
-                                            [texture.width as f32, texture.height as f32],
-                                        );
-                                        if !ui.not_in_view(&(texture.height as f32)) {
+                                        ui.invisible_button(href, [width, height]);
+                                        if !ui.not_in_view(&height) {
                                             ui.get_window_draw_list()
                                                 .add_image(
                                                     texture.id(),
@@ -309,12 +432,29 @@ impl Context {
                                                     ui.item_rect_max(),
                                                 )
                                                 .build();
-                                            // if ui.is_item_clicked() {
-                                            //     log::info!("Clicked on image: {}", href);
-                                            // }
+                                        }
+                                        if ui.is_item_hovered() {
+                                            ui.tooltip_text("Click to enlarge");
+                                        }
+                                        if ui.is_item_clicked() {
+                                            if let Some(lightbox) =
+                                                Lightbox::from_tokens(&popup.data.images, href)
+                                            {
+                                                ui_actions.push(UiAction::OpenLightbox(lightbox));
+                                            }
                                         }
                                         ui.spacing();
                                     }
+                                } else if let CachingStatus::Failed = cached_data.caching_status {
+                                    let text = "Image unavailable.";
+                                    let text_size = ui.calc_text_size(text)[0];
+                                    let window_width = ui.window_size()[0];
+                                    ui.set_cursor_pos([
+                                        window_width / 2.0 - text_size / 2.0,
+                                        ui.cursor_pos()[1],
+                                    ]);
+                                    ui.text_disabled(text);
+                                    ui.spacing();
                                 }
                             }
                         }
@@ -378,6 +518,7 @@ impl Context {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_ribbon(
         ui: &Ui<'_>,
         pinned_popup_index: Option<usize>,
@@ -388,6 +529,7 @@ impl Context {
         item_ids: Option<Vec<u32>>,
         item_quantity: usize,
     ) {
+        let price_item_ids = priced_item_ids(&popup.data.title, &item_ids);
         ui.separator();
         ui.text_colored(rendering_params.link_color, "Open wiki");
         if ui.is_item_clicked() {
@@ -423,12 +565,63 @@ impl Context {
             if MenuItem::new(format!("Copy name##idp{}", popup.state.id)).build(ui) {
                 copy_popup_title(popup)
             }
+            if MenuItem::new(format!("Copy as Markdown##idp{}", popup.state.id)).build(ui) {
+                let shown_prices = Self::shown_prices(cache, &price_item_ids);
+                copy_popup_content(popup, shown_prices, ExportFormat::Markdown);
+            }
+            if MenuItem::new(format!("Copy as text##idp{}", popup.state.id)).build(ui) {
+                let shown_prices = Self::shown_prices(cache, &price_item_ids);
+                copy_popup_content(popup, shown_prices, ExportFormat::Text);
+            }
+            if let Some(item_id) = price_item_ids.as_ref().and_then(|ids| ids.first()) {
+                if MenuItem::new(format!("Add to watchlist##idp{}", popup.state.id)).build(ui) {
+                    write_config()
+                        .watchlist
+                        .add(*item_id, popup.data.title.clone());
+                }
+            }
+            if pinned_popup_index.is_some()
+                && MenuItem::new(format!("Compare with..##idp{}", popup.state.id)).build(ui)
+            {
+                ui_actions.push(UiAction::Compare(popup.state.id));
+            }
             if MenuItem::new(format!("Close all##idp{}", popup.state.id)).build(ui) {
                 close_all_popups();
             }
         });
         ui.same_line();
-        Self::render_prices(ui, &item_ids, cache, rendering_params, item_quantity);
+        Self::render_prices(ui, &price_item_ids, cache, rendering_params, item_quantity);
+        Self::render_variants(
+            ui,
+            popup.state.id,
+            &popup.data.title,
+            &item_ids,
+            cache,
+            rendering_params,
+        );
+        Self::render_listings(ui, popup.state.id, &price_item_ids, cache, rendering_params);
+        Self::render_profit(
+            ui,
+            &mut popup.state,
+            &price_item_ids,
+            cache,
+            rendering_params,
+        );
+        Self::render_contents(
+            ui,
+            popup.state.id,
+            &popup.data.contents,
+            &price_item_ids,
+            cache,
+            rendering_params,
+        );
+    }
+
+    // (lowest sell, highest buy) as shown in the ribbon, only needed when copying
+    fn shown_prices(cache: &mut Cache, price_item_ids: &Option<Vec<u32>>) -> Option<(u32, u32)> {
+        let prices = cache.prices.retrieve(price_item_ids.clone()?)?;
+        let price = prices.get(&highest_sell_item_id(&prices)?)?.value()?;
+        Some((price.lowest_sell, price.highest_buy))
     }
 
     fn render_tag_bar(
@@ -453,6 +646,7 @@ impl Context {
                     ui_actions.push(UiAction::Open(UiLink {
                         href: tag.0.clone(),
                         title: tag.1.clone(),
+                        popup_id: popup.state.id,
                     }));
                 }
                 ui.same_line();
@@ -0,0 +1,69 @@
+use crate::state::cache::{Cache, StoreInCache};
+use crate::state::context::Context;
+use crate::state::popup::navigation::NavigationEntry;
+use crate::state::popup::Popup;
+use crate::state::threads::link::navigate_popup_thread;
+use nexus::imgui::{Direction, MouseButton, Ui, WindowHoveredFlags};
+
+enum NavigationDirection {
+    Back,
+    Forward,
+}
+
+impl Context {
+    pub fn render_navigation_buttons(ui: &Ui, popup: &mut Popup, cache: &mut Cache) {
+        let mut direction = None;
+        if !popup.state.navigation.is_empty() {
+            for (label, arrow, enabled, button_direction) in [
+                (
+                    "back",
+                    Direction::Left,
+                    popup.state.navigation.can_go_back(),
+                    NavigationDirection::Back,
+                ),
+                (
+                    "forward",
+                    Direction::Right,
+                    popup.state.navigation.can_go_forward(),
+                    NavigationDirection::Forward,
+                ),
+            ] {
+                let _disabled = ui.begin_disabled(!enabled);
+                if ui.arrow_button(format!("##{label}_idp{}", popup.state.id), arrow) {
+                    direction = Some(button_direction);
+                }
+                ui.same_line();
+            }
+        }
+        if ui.is_window_hovered_with_flags(WindowHoveredFlags::CHILD_WINDOWS) {
+            if ui.is_mouse_clicked(MouseButton::Extra1) {
+                direction = Some(NavigationDirection::Back);
+            } else if ui.is_mouse_clicked(MouseButton::Extra2) {
+                direction = Some(NavigationDirection::Forward);
+            }
+        }
+        let Some(direction) = direction else { return };
+        let current = popup.navigation_entry();
+        let target = match direction {
+            NavigationDirection::Back => popup.state.navigation.go_back(current),
+            NavigationDirection::Forward => popup.state.navigation.go_forward(current),
+        };
+        if let Some(target) = target {
+            Self::navigate(popup, target, cache);
+        }
+    }
+
+    pub(crate) fn navigate(popup: &mut Popup, target: NavigationEntry, cache: &mut Cache) {
+        popup.state.item_quantity = 1;
+        popup.state.custom_sell_price = None;
+        match cache.popup_data_map.retrieve(&target.href) {
+            Some(mut data) => {
+                data.item_ids = target.item_ids;
+                popup.data = data;
+            }
+            None => {
+                navigate_popup_thread(popup.state.id, target.href, target.title, target.item_ids)
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::render::ui::{UiAction, UiExtended};
+use crate::state::cache::Cache;
+use crate::state::context::Context;
+use crate::state::popup::Popup;
+use nexus::imgui::Ui;
+
+const NOTE_COLOR: [f32; 4] = [0.95, 0.8, 0.3, 1.0];
+const NOTE_INPUT_HEIGHT: f32 = 150.0;
+const NOTES_TAB: &str = "My notes";
+const NOTE_INDICATOR: &str = "[Note]";
+
+impl Context {
+    pub fn render_note_indicator(ui: &Ui, popup: &Popup, cache: &Cache, height: Option<f32>) {
+        let Some(note) = cache.notes.get(&popup.data.href) else {
+            return;
+        };
+        if let Some(height) = height {
+            ui.align_vert_centered(NOTE_INDICATOR, &height);
+        }
+        ui.text_colored(NOTE_COLOR, NOTE_INDICATOR);
+        if ui.is_item_hovered() {
+            ui.tooltip_text(&note.text);
+        }
+        ui.same_line();
+    }
+
+    pub fn render_notes_tab(
+        ui: &Ui<'_>,
+        pinned_popup_index: Option<usize>,
+        popup: &mut Popup,
+        ui_actions: &mut Vec<UiAction>,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        let token = Self::popup_tab_item(ui, NOTES_TAB, &mut popup.state);
+        if ui.is_item_hovered()
+            && pinned_popup_index.is_none()
+            && rendering_params.auto_pin_on_tab_hover
+        {
+            Self::pin_popup(ui, &mut popup.state, ui_actions);
+        }
+        if token.is_none() {
+            return;
+        }
+        let mut text = cache
+            .notes
+            .get(&popup.data.href)
+            .map(|note| note.text.clone())
+            .unwrap_or_default();
+        if !popup.state.pinned {
+            ui.text_disabled("Pin the popup to edit your note.");
+            if !text.is_empty() {
+                ui.text_wrapped(&text);
+            }
+            return;
+        }
+        if ui
+            .input_text_multiline(
+                format!("##notes_idp{}", popup.state.id),
+                &mut text,
+                [
+                    rendering_params
+                        .max_content_width
+                        .min(ui.content_region_avail()[0]),
+                    NOTE_INPUT_HEIGHT,
+                ],
+            )
+            .build()
+        {
+            cache.notes.set(&popup.data.href, &popup.data.title, text);
+        }
+        if let Some(note) = cache.notes.get(&popup.data.href) {
+            ui.text_disabled(format!(
+                "Last edited {}",
+                note.date.format("%Y-%m-%d %H:%M")
+            ));
+        }
+    }
+}
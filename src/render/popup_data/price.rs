@@ -1,12 +1,23 @@
 use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::configuration::read_config;
+use crate::service::trading_post::{break_even_price, exchange_fee, listing_fee, net_proceeds};
+use crate::state::cache::caching_status::CachingStatus;
+use crate::state::cache::listings::walk_book;
+use crate::state::cache::price::PriceCache;
 use crate::state::cache::Cache;
 use crate::state::cache::StoreInCache;
 use crate::state::context::Context;
-use nexus::imgui::Ui;
+use crate::state::popup::popup_state::PopupState;
+use nexus::imgui::{TableFlags, TreeNodeFlags, Ui};
 
 pub const GOLD_COIN_HREF: &str = "/images/thumb/d/d1/Gold_coin.png/18px-Gold_coin.png";
 pub const SILVER_COIN_HREF: &str = "/images/thumb/3/3c/Silver_coin.png/18px-Silver_coin.png";
 pub const COPPER_COIN_HREF: &str = "/images/thumb/e/eb/Copper_coin.png/18px-Copper_coin.png";
+pub(crate) const LOSS_COLOR: [f32; 4] = [0.9, 0.2, 0.2, 1.0];
+const PRICE_INPUT_GOLD_WIDTH: f32 = 60.0;
+const PRICE_INPUT_WIDTH: f32 = 30.0;
+// keeps the total below u32::MAX copper
+const MAX_PRICE_INPUT_GOLD: i32 = 400_000;
 
 impl Context {
     pub fn render_price(
@@ -46,63 +57,305 @@ impl Context {
             return;
         };
 
-        let mut highest_sell_price = None;
-        for (item_id, price_data) in &prices {
-            if let Some(price) = price_data.value() {
-                match highest_sell_price {
-                    None => highest_sell_price = Some((*item_id, price.lowest_sell)),
-                    Some((_, current_max)) if price.lowest_sell > current_max => {
-                        highest_sell_price = Some((*item_id, price.lowest_sell))
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        if let Some(price) = highest_sell_price
-            .and_then(|(item_id, _)| prices.get(&item_id))
+        let Some(item_id) = highest_sell_item_id(&prices) else {
+            return;
+        };
+        let Some(price) = prices
+            .get(&item_id)
             .and_then(|cached_price| cached_price.value())
-        {
-            ui.text_disabled(" | ");
-            ui.same_line();
-            ui.text("Sell ");
-            ui.same_line();
-            ui.group(|| {
-                Self::render_price(ui, price.lowest_sell, cache, rendering_params);
-            });
-            if ui.is_item_hovered() && item_quantity > 1 {
-                ui.tooltip(|| {
-                    ui.text_disabled(format!(" Sell {item_quantity} for: "));
-                    ui.same_line();
-                    Self::render_price(
+        else {
+            return;
+        };
+        let has_price_history = cache.price_history.contains_key(&item_id);
+        ui.text_disabled(" | ");
+        ui.same_line();
+        ui.text("Sell ");
+        ui.same_line();
+        ui.group(|| {
+            Self::render_price(ui, price.lowest_sell, cache, rendering_params);
+        });
+        if ui.is_item_hovered() && (item_quantity > 1 || has_price_history) {
+            ui.tooltip(|| {
+                if item_quantity > 1 {
+                    Self::render_book_walk(
                         ui,
-                        price.lowest_sell * item_quantity as u32,
+                        item_id,
+                        BookSide::Sells,
+                        price.lowest_sell,
                         cache,
                         rendering_params,
+                        item_quantity,
                     );
-                });
-            }
-            ui.same_line();
-            ui.text_disabled(" | ");
-            ui.same_line();
-            ui.text("Buy ");
-            ui.same_line();
-            ui.group(|| {
-                Self::render_price(ui, price.highest_buy, cache, rendering_params);
+                }
+                Self::render_item_price_history(ui, item_id, cache, rendering_params);
             });
-            if ui.is_item_hovered() && item_quantity > 1 {
-                ui.tooltip(|| {
-                    ui.text_disabled(format!(" Buy {item_quantity} for: "));
-                    ui.same_line();
-                    Self::render_price(
+        }
+        ui.same_line();
+        ui.text_disabled(" | ");
+        ui.same_line();
+        ui.text("Buy ");
+        ui.same_line();
+        ui.group(|| {
+            Self::render_price(ui, price.highest_buy, cache, rendering_params);
+        });
+        if ui.is_item_hovered() && (item_quantity > 1 || has_price_history) {
+            ui.tooltip(|| {
+                if item_quantity > 1 {
+                    Self::render_book_walk(
                         ui,
-                        price.highest_buy * item_quantity as u32,
+                        item_id,
+                        BookSide::Buys,
+                        price.highest_buy,
                         cache,
                         rendering_params,
+                        item_quantity,
                     );
-                });
+                }
+                Self::render_item_price_history(ui, item_id, cache, rendering_params);
+            });
+        }
+    }
+
+    fn render_item_price_history(
+        ui: &Ui,
+        item_id: u32,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        // taken out while rendering instead of copied, prices need the cache for coin textures
+        if let Some(price_history) = cache.price_history.remove(&item_id) {
+            Self::render_price_history(ui, &price_history, cache, rendering_params);
+            cache.price_history.insert(item_id, price_history);
+        }
+    }
+
+    fn render_book_walk(
+        ui: &Ui,
+        item_id: u32,
+        side: BookSide,
+        top_price: u32,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+        item_quantity: usize,
+    ) {
+        let label = match side {
+            BookSide::Sells => format!(" Buy {item_quantity} from sell listings: "),
+            BookSide::Buys => format!(" Sell {item_quantity} to buy orders: "),
+        };
+        let listings = cache.listings.retrieve(item_id);
+        let Some(listings) = listings.as_ref().and_then(|listings| listings.value()) else {
+            ui.text_disabled(format!("{label}~"));
+            ui.same_line();
+            Self::render_price(
+                ui,
+                top_price.saturating_mul(item_quantity as u32),
+                cache,
+                rendering_params,
+            );
+            return;
+        };
+        let levels = match side {
+            BookSide::Sells => &listings.sells,
+            BookSide::Buys => &listings.buys,
+        };
+        let book_walk = walk_book(levels, item_quantity as u32);
+        ui.text_disabled(label);
+        ui.same_line();
+        Self::render_price(ui, book_walk.total, cache, rendering_params);
+        ui.text_disabled(" Average unit price: ");
+        ui.same_line();
+        Self::render_price(ui, book_walk.average_unit_price(), cache, rendering_params);
+        if book_walk.quantity < item_quantity as u32 {
+            ui.text_disabled(format!(" Only {} available", book_walk.quantity));
+        }
+    }
+
+    pub fn render_listings(
+        ui: &Ui,
+        popup_id: u64,
+        item_ids: &Option<Vec<u32>>,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        let Some(item_ids) = item_ids else { return };
+        let Some(item_id) = cache
+            .prices
+            .retrieve(item_ids.clone())
+            .and_then(|prices| highest_sell_item_id(&prices))
+        else {
+            return;
+        };
+        if !ui.collapsing_header(
+            format!("Listings##idp{popup_id}"),
+            TreeNodeFlags::SPAN_AVAIL_WIDTH,
+        ) {
+            return;
+        }
+        let cached_listings = cache.listings.retrieve(item_id);
+        let Some(listings) = cached_listings
+            .as_ref()
+            .and_then(|listings| listings.value())
+        else {
+            match cached_listings.map(|listings| listings.caching_status) {
+                Some(CachingStatus::Failed) => ui.text_disabled("Could not load listings."),
+                _ => ui.text_disabled("Loading listings.."),
             }
+            return;
+        };
+        for (label, levels) in [
+            ("Sell listings", &listings.sells),
+            ("Buy orders", &listings.buys),
+        ] {
+            ui.text_disabled(label);
+            let Some(_t) = ui.begin_table_with_flags(
+                format!("{label}##idp{popup_id}"),
+                3,
+                TableFlags::NO_SAVED_SETTINGS | TableFlags::ROW_BG,
+            ) else {
+                continue;
+            };
+            for header in ["Price", "Quantity", "Cumulative"] {
+                ui.table_setup_column(header);
+            }
+            ui.table_headers_row();
+            let mut cumulative = 0;
+            for level in levels.iter().take(rendering_params.listings_depth) {
+                cumulative += level.quantity;
+                ui.table_next_row();
+                ui.table_next_column();
+                Self::render_price(ui, level.unit_price, cache, rendering_params);
+                ui.table_next_column();
+                ui.text(level.quantity.to_string());
+                ui.table_next_column();
+                ui.text(cumulative.to_string());
+            }
+        }
+    }
+
+    pub fn render_profit(
+        ui: &Ui,
+        popup_state: &mut PopupState,
+        item_ids: &Option<Vec<u32>>,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        let Some(item_ids) = item_ids else { return };
+        let Some(prices) = cache.prices.retrieve(item_ids.clone()) else {
+            return;
+        };
+        let Some(price) = highest_sell_item_id(&prices)
+            .and_then(|item_id| prices.get(&item_id))
+            .and_then(|cached_price| cached_price.value())
+        else {
+            return;
+        };
+        if !ui.collapsing_header(
+            format!("Profit##idp{}", popup_state.id),
+            TreeNodeFlags::SPAN_AVAIL_WIDTH,
+        ) {
+            return;
         }
+        let quantity = popup_state.item_quantity as u32;
+        let mut use_custom_price = popup_state.custom_sell_price.is_some();
+        if ui.checkbox(
+            format!("Custom sell price##idp{}", popup_state.id),
+            &mut use_custom_price,
+        ) {
+            popup_state.custom_sell_price = use_custom_price.then_some(price.lowest_sell);
+        }
+        if let Some(custom_sell_price) = &mut popup_state.custom_sell_price {
+            Self::render_price_input(
+                ui,
+                &format!("custom_sell_price_{}", popup_state.id),
+                custom_sell_price,
+            );
+        }
+        let sell_price = popup_state.custom_sell_price.unwrap_or(price.lowest_sell);
+        let net_proceeds = net_proceeds(sell_price);
+
+        Self::render_profit_row(
+            ui,
+            &format!(" Listing fee (5%) for {quantity}: "),
+            listing_fee(sell_price).saturating_mul(quantity),
+            cache,
+            rendering_params,
+        );
+        Self::render_profit_row(
+            ui,
+            &format!(" Exchange fee (10%) for {quantity}: "),
+            exchange_fee(sell_price).saturating_mul(quantity),
+            cache,
+            rendering_params,
+        );
+        Self::render_profit_row(
+            ui,
+            &format!(" Net proceeds for {quantity}: "),
+            net_proceeds.saturating_mul(quantity),
+            cache,
+            rendering_params,
+        );
+        Self::render_profit_row(
+            ui,
+            " Break-even sell price: ",
+            break_even_price(price.highest_buy),
+            cache,
+            rendering_params,
+        );
+        let margin = net_proceeds as i64 - price.highest_buy as i64;
+        ui.text_disabled(format!(" Margin vs highest buy for {quantity}: "));
+        ui.same_line();
+        if margin < 0 {
+            ui.text_colored(LOSS_COLOR, "-");
+            ui.same_line();
+        }
+        Self::render_price(
+            ui,
+            (margin.unsigned_abs() as u32).saturating_mul(quantity),
+            cache,
+            rendering_params,
+        );
+    }
+
+    fn render_profit_row(
+        ui: &Ui,
+        label: &str,
+        price: u32,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        ui.text_disabled(label);
+        ui.same_line();
+        Self::render_price(ui, price, cache, rendering_params);
+    }
+
+    pub fn render_price_input(ui: &Ui, id: &str, price: &mut u32) -> bool {
+        let mut gold = Self::gold_price_part(*price) as i32;
+        let mut silver = Self::silver_price_part(*price) as i32;
+        let mut copper = Self::copper_price_part(*price) as i32;
+        let mut changed = false;
+        ui.set_next_item_width(PRICE_INPUT_GOLD_WIDTH);
+        changed |= ui
+            .input_int(format!("g##{id}_idp"), &mut gold)
+            .step(0)
+            .build();
+        ui.same_line();
+        ui.set_next_item_width(PRICE_INPUT_WIDTH);
+        changed |= ui
+            .input_int(format!("s##{id}_idp"), &mut silver)
+            .step(0)
+            .build();
+        ui.same_line();
+        ui.set_next_item_width(PRICE_INPUT_WIDTH);
+        changed |= ui
+            .input_int(format!("c##{id}_idp"), &mut copper)
+            .step(0)
+            .build();
+        if changed {
+            *price = gold.clamp(0, MAX_PRICE_INPUT_GOLD) as u32 * 10000
+                + silver.clamp(0, 99) as u32 * 100
+                + copper.clamp(0, 99) as u32;
+        }
+        changed
     }
 
     fn gold_price_part(price: u32) -> u32 {
@@ -117,3 +370,29 @@ impl Context {
         price % 100
     }
 }
+
+enum BookSide {
+    Sells,
+    Buys,
+}
+
+// A preferred variant chosen for the title is priced instead of all of its ids.
+pub(crate) fn priced_item_ids(title: &str, item_ids: &Option<Vec<u32>>) -> Option<Vec<u32>> {
+    let item_ids = item_ids.as_ref()?;
+    match read_config().preferred_item_variants.get(title) {
+        Some(preferred_id) if item_ids.contains(preferred_id) => Some(vec![*preferred_id]),
+        _ => Some(item_ids.clone()),
+    }
+}
+
+pub(crate) fn highest_sell_item_id(prices: &PriceCache) -> Option<u32> {
+    prices
+        .iter()
+        .filter_map(|(item_id, cached_price)| {
+            cached_price
+                .value()
+                .map(|price| (*item_id, price.lowest_sell))
+        })
+        .max_by_key(|(_, lowest_sell)| *lowest_sell)
+        .map(|(item_id, _)| item_id)
+}
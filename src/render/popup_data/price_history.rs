@@ -0,0 +1,129 @@
+use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::state::cache::price_history::{history_since, PricePoint};
+use crate::state::cache::Cache;
+use crate::state::context::Context;
+use chrono::TimeDelta;
+use nexus::imgui::Ui;
+
+const CHART_SIZE: [f32; 2] = [220.0, 40.0];
+const CHART_BORDER_COLOR: [f32; 4] = [0.5, 0.5, 0.5, 1.0];
+
+struct PriceRange {
+    min_sell: u32,
+    max_sell: u32,
+    min_buy: u32,
+    max_buy: u32,
+}
+
+impl PriceRange {
+    fn of(points: &[PricePoint]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let initial = Self {
+            min_sell: first.lowest_sell,
+            max_sell: first.lowest_sell,
+            min_buy: first.highest_buy,
+            max_buy: first.highest_buy,
+        };
+        Some(rest.iter().fold(initial, |range, point| Self {
+            min_sell: range.min_sell.min(point.lowest_sell),
+            max_sell: range.max_sell.max(point.lowest_sell),
+            min_buy: range.min_buy.min(point.highest_buy),
+            max_buy: range.max_buy.max(point.highest_buy),
+        }))
+    }
+}
+
+impl Context {
+    pub fn render_price_history(
+        ui: &Ui,
+        points: &[PricePoint],
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        for (label, period) in [
+            ("Last day", TimeDelta::days(1)),
+            ("Last week", TimeDelta::weeks(1)),
+            ("Last month", TimeDelta::days(30)),
+        ] {
+            let points = history_since(points, period);
+            ui.spacing();
+            ui.text_disabled(label);
+            let range = match PriceRange::of(points) {
+                Some(range) if points.len() >= 2 => range,
+                _ => {
+                    ui.text_disabled(" Not enough data yet");
+                    continue;
+                }
+            };
+            Self::render_price_chart(ui, points, &range, rendering_params);
+            ui.text_colored(rendering_params.gold_coin_color, " Sell ");
+            ui.same_line();
+            Self::render_price(ui, range.min_sell, cache, rendering_params);
+            ui.same_line();
+            ui.text_disabled(" - ");
+            ui.same_line();
+            Self::render_price(ui, range.max_sell, cache, rendering_params);
+            ui.text_colored(rendering_params.silver_coin_color, " Buy ");
+            ui.same_line();
+            Self::render_price(ui, range.min_buy, cache, rendering_params);
+            ui.same_line();
+            ui.text_disabled(" - ");
+            ui.same_line();
+            Self::render_price(ui, range.max_buy, cache, rendering_params);
+        }
+    }
+
+    fn render_price_chart(
+        ui: &Ui,
+        points: &[PricePoint],
+        range: &PriceRange,
+        rendering_params: &RenderingParams,
+    ) {
+        let [left, top] = ui.cursor_screen_pos();
+        let [width, height] = CHART_SIZE;
+        ui.dummy(CHART_SIZE);
+
+        let min = range.min_sell.min(range.min_buy) as f32;
+        let max = range.max_sell.max(range.max_buy) as f32;
+        let first_date = points[0].date;
+        let duration = (points[points.len() - 1].date - first_date)
+            .num_seconds()
+            .max(1) as f32;
+        let to_screen = |point: &PricePoint, price: u32| {
+            let x = (point.date - first_date).num_seconds() as f32 / duration;
+            let y = if max > min {
+                (price as f32 - min) / (max - min)
+            } else {
+                0.5
+            };
+            [left + x * width, top + (1.0 - y) * height]
+        };
+
+        let draw_list = ui.get_window_draw_list();
+        draw_list
+            .add_rect(
+                [left, top],
+                [left + width, top + height],
+                CHART_BORDER_COLOR,
+            )
+            .build();
+        draw_list
+            .add_polyline(
+                points
+                    .iter()
+                    .map(|point| to_screen(point, point.lowest_sell))
+                    .collect(),
+                rendering_params.gold_coin_color,
+            )
+            .build();
+        draw_list
+            .add_polyline(
+                points
+                    .iter()
+                    .map(|point| to_screen(point, point.highest_buy))
+                    .collect(),
+                rendering_params.silver_coin_color,
+            )
+            .build();
+    }
+}
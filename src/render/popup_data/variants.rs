@@ -0,0 +1,98 @@
+use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::configuration::{read_config, write_config};
+use crate::state::cache::Cache;
+use crate::state::cache::StoreInCache;
+use crate::state::context::Context;
+use nexus::imgui::{TableFlags, TreeNodeFlags, Ui};
+
+impl Context {
+    pub fn render_variants(
+        ui: &Ui,
+        popup_id: u64,
+        title: &str,
+        item_ids: &Option<Vec<u32>>,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        let Some(item_ids) = item_ids else { return };
+        if item_ids.len() < 2 {
+            return;
+        }
+        if !ui.collapsing_header(
+            format!("Variants ({})##idp{popup_id}", item_ids.len()),
+            TreeNodeFlags::SPAN_AVAIL_WIDTH,
+        ) {
+            return;
+        }
+        let Some(prices) = cache.prices.retrieve(item_ids.clone()) else {
+            return;
+        };
+        let Some(item_details) = cache.item_details.retrieve(item_ids.clone()) else {
+            return;
+        };
+        let preferred_item_id = read_config()
+            .preferred_item_variants
+            .get(title)
+            .copied()
+            .filter(|item_id| item_ids.contains(item_id));
+
+        if ui.radio_button_bool(
+            format!("Highest sell price##idp{popup_id}"),
+            preferred_item_id.is_none(),
+        ) {
+            write_config().preferred_item_variants.remove(title);
+        }
+        let Some(_t) = ui.begin_table_with_flags(
+            format!("variants##idp{popup_id}"),
+            6,
+            TableFlags::NO_SAVED_SETTINGS | TableFlags::ROW_BG | TableFlags::SIZING_FIXED_FIT,
+        ) else {
+            return;
+        };
+        for header in ["", "Id", "Rarity", "Buy", "Sell", "Tradable"] {
+            ui.table_setup_column(header);
+        }
+        ui.table_headers_row();
+        for item_id in item_ids {
+            let details = item_details
+                .get(item_id)
+                .and_then(|details| details.value());
+            let price = prices.get(item_id).and_then(|price| price.value());
+            ui.table_next_row();
+            ui.table_next_column();
+            if ui.radio_button_bool(
+                format!("##variant{item_id}idp{popup_id}"),
+                preferred_item_id == Some(*item_id),
+            ) {
+                write_config()
+                    .preferred_item_variants
+                    .insert(title.to_string(), *item_id);
+            }
+            ui.table_next_column();
+            ui.text(item_id.to_string());
+            ui.table_next_column();
+            match details {
+                Some(details) => ui.text(&details.rarity),
+                None => ui.text_disabled("-"),
+            }
+            for unit_price in [
+                price.map(|price| price.highest_buy),
+                price.map(|price| price.lowest_sell),
+            ] {
+                ui.table_next_column();
+                match unit_price {
+                    Some(unit_price) if unit_price > 0 => {
+                        Self::render_price(ui, unit_price, cache, rendering_params)
+                    }
+                    _ => ui.text_disabled("-"),
+                }
+            }
+            ui.table_next_column();
+            match details.map(|details| details.tradable) {
+                Some(true) => ui.text("Yes"),
+                Some(false) => ui.text_disabled("No"),
+                None => ui.text_disabled("-"),
+            }
+        }
+    }
+}
@@ -35,6 +35,7 @@ impl Context {
 
             if !self.ui.search_popup_input.is_empty() {
                 let needle = self.ui.search_popup_input.as_str();
+                let notes = self.cache.notes.find_containing(needle, MAX_SEARCH_RESULTS);
                 let link_color = read_config().rendering_params.link_color;
                 for entry in read_config()
                     .search_params
@@ -47,6 +48,20 @@ impl Context {
                         should_search = true;
                     }
                 }
+                if !notes.is_empty() {
+                    ui.spacing();
+                    ui.text_disabled("My notes:");
+                }
+                for (href, note) in notes {
+                    ui.text_colored(link_color, &note.title);
+                    if ui.is_item_hovered() {
+                        ui.tooltip_text(&note.text);
+                    }
+                    if ui.is_item_clicked() {
+                        ui.close_current_popup();
+                        open_link_thread(href.clone(), note.title.clone());
+                    }
+                }
             }
 
             if ui.is_key_released(Key::Enter) || should_search {
@@ -1,9 +1,10 @@
 use crate::render::ui::UiExtended;
 use crate::state::cache::caching_status::CachingStatus;
+use crate::state::cache::texture::sized_texture_href;
 use crate::state::cache::{Cache, StoreInCache};
 use crate::state::context::Context;
 use crate::state::popup::dimensions::Dimensions;
-use nexus::imgui::Ui;
+use nexus::imgui::{StyleColor, Ui};
 
 impl Context {
     pub fn render_image(
@@ -20,7 +21,11 @@ impl Context {
             return output;
         }
 
-        let cached_data_opt = cache.textures.retrieve(href.to_string());
+        let texture_href = match dimensions {
+            Some(d) => sized_texture_href(href, d.width.max(d.height) * ui.ui_scale()),
+            None => href.to_string(),
+        };
+        let cached_data_opt = cache.textures.retrieve(texture_href);
         if let Some(cached_data) = cached_data_opt {
             match cached_data.caching_status {
                 CachingStatus::Cached => {
@@ -36,6 +41,10 @@ impl Context {
                         return Some(Dimensions::new(width, height));
                     }
                 }
+                CachingStatus::Failed => {
+                    let dimensions = dimensions.clone().unwrap_or_else(Dimensions::small);
+                    return Self::render_failed_placeholder(ui, &dimensions, href);
+                }
                 _ => {
                     return dimensions
                         .as_ref()
@@ -51,4 +60,26 @@ impl Context {
         ui.invisible_button(href, [width, height]);
         Some(dimensions.clone())
     }
+
+    // Crossed out, so images that failed to load don't look like they are still loading.
+    fn render_failed_placeholder(
+        ui: &Ui,
+        dimensions: &Dimensions,
+        href: &str,
+    ) -> Option<Dimensions> {
+        let output = Self::render_placeholder(ui, dimensions, href);
+        let min = ui.item_rect_min();
+        let max = ui.item_rect_max();
+        let color = ui.style_color(StyleColor::TextDisabled);
+        let draw_list = ui.get_window_draw_list();
+        draw_list.add_rect(min, max, color).build();
+        draw_list.add_line(min, max, color).build();
+        draw_list
+            .add_line([min[0], max[1]], [max[0], min[1]], color)
+            .build();
+        if ui.is_item_hovered() {
+            ui.tooltip_text("Image unavailable");
+        }
+        output
+    }
 }
@@ -9,14 +9,18 @@ use crate::render::ui::UiAction;
 use crate::state::cache::Cache;
 use crate::state::context::Context;
 use crate::state::font::Font;
+use crate::state::popup::find::TextHighlight;
+use crate::state::popup::table_state::TableState;
 use crate::state::popup::token::Token;
 use log::debug;
 use nexus::imgui::StyleVar::ItemSpacing;
 use nexus::imgui::Ui;
+use std::collections::HashMap;
 
 const INDENT: &str = "    ";
 
 impl Context {
+    #[allow(clippy::too_many_arguments)]
     pub fn render_tokens(
         ui: &Ui,
         pinned: &mut bool,
@@ -28,6 +32,8 @@ impl Context {
         bold_font: &Option<Font>,
         rendering_params: &RenderingParams,
         render_tables: bool,
+        table_states: &mut HashMap<String, TableState>,
+        highlight: &mut Option<TextHighlight>,
     ) {
         debug!("[render_tokens]");
         let item_spacing_style = ui.push_style_var(ItemSpacing([0.0, 5.0]));
@@ -46,17 +52,26 @@ impl Context {
                 Token::Spacing => {
                     ui.spacing();
                 }
-                Token::Text(text, style) => {
-                    Self::render_text(ui, text, style, current_indent, rendering_params, bold_font)
-                }
+                Token::Text(text, style) => Self::render_text(
+                    ui,
+                    text,
+                    style,
+                    current_indent,
+                    rendering_params,
+                    bold_font,
+                    highlight,
+                ),
                 Token::Tag(tag_params) => {
                     Self::render_tag(
                         ui,
                         tag_params,
                         pinned,
+                        popup_id,
                         ui_actions,
                         current_indent,
+                        cache,
                         rendering_params,
+                        highlight,
                     );
                 }
                 Token::ListElement => Self::render_list_element(
@@ -82,6 +97,8 @@ impl Context {
                             bold_font,
                             rendering_params,
                             table_params,
+                            table_states,
+                            highlight,
                         )
                     }
                 }
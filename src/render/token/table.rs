@@ -3,11 +3,18 @@ use crate::render::ui::UiAction;
 use crate::state::cache::Cache;
 use crate::state::context::Context;
 use crate::state::font::Font;
-use crate::state::popup::table_params::TableParams;
+use crate::state::popup::find::TextHighlight;
+use crate::state::popup::table_params::{CellSpan, TableParams};
+use crate::state::popup::table_state::TableState;
 use log::debug;
-use nexus::imgui::{TableFlags, Ui};
+use nexus::imgui::{TableFlags, TableRowFlags, Ui};
+use std::collections::HashMap;
+
+const MIN_ROWS_FOR_CONTROLS: usize = 5;
+const TABLE_FILTER_WIDTH: f32 = 200.0;
 
 impl Context {
+    #[allow(clippy::too_many_arguments)]
     pub fn render_table(
         ui: &Ui,
         pinned: &mut bool,
@@ -18,21 +25,47 @@ impl Context {
         bold_font: &Option<Font>,
         rendering_params: &RenderingParams,
         table_params: &TableParams,
+        table_states: &mut HashMap<String, TableState>,
+        highlight: &mut Option<TextHighlight>,
     ) {
         debug!("[render_table] {table_params:?}");
+        let table_state = table_states.entry(table_params.uuid.clone()).or_default();
+        Self::render_table_controls(ui, table_params, table_state);
+        let visible_columns = table_state.visible_columns(table_params);
+        if visible_columns.is_empty() {
+            return;
+        }
         if let Some(_t) = ui.begin_table_with_flags(
             format!(
                 "table_{}_{}_{}##idp",
                 table_params.uuid, popup_id, section_label
             ),
-            table_params.headers.len(),
+            visible_columns.len(),
             TableFlags::RESIZABLE | TableFlags::NO_SAVED_SETTINGS,
         ) {
             debug!("[render_table setup headers]");
-            for header in &table_params.headers {
-                ui.table_setup_column(header);
+            for column in &visible_columns {
+                ui.table_setup_column(&table_params.headers[*column]);
+            }
+            ui.table_next_row_with_flags(TableRowFlags::HEADERS);
+            for column in &visible_columns {
+                ui.table_next_column();
+                let sort_indicator = match table_state.sort_column {
+                    Some(sort_column) if sort_column == *column && table_state.sort_ascending => {
+                        " (asc)"
+                    }
+                    Some(sort_column) if sort_column == *column => " (desc)",
+                    _ => "",
+                };
+                ui.table_header(format!(
+                    "{}{sort_indicator}##header_{column}",
+                    table_params.headers[*column]
+                ));
+                if ui.is_item_clicked() {
+                    table_state.toggle_sort(*column);
+                }
             }
-            ui.table_headers_row();
+            let visible_rows = table_state.visible_rows(table_params);
             Self::render_table_rows(
                 ui,
                 pinned,
@@ -43,10 +76,56 @@ impl Context {
                 bold_font,
                 rendering_params,
                 table_params,
+                &visible_rows,
+                &visible_columns,
+                table_state.sort_column.is_none() && table_state.filter.trim().is_empty(),
+                table_states,
+                highlight,
             );
         }
     }
 
+    fn render_table_controls(ui: &Ui, table_params: &TableParams, table_state: &mut TableState) {
+        if table_params.rows.len() < MIN_ROWS_FOR_CONTROLS {
+            return;
+        }
+        ui.set_next_item_width(TABLE_FILTER_WIDTH);
+        ui.input_text(
+            format!("##table_filter_idp{}", table_params.uuid),
+            &mut table_state.filter,
+        )
+        .hint("Filter..")
+        .build();
+        ui.same_line();
+        if ui.small_button(format!("Columns..##table_columns_idp{}", table_params.uuid)) {
+            ui.open_popup(format!("##table_columns_popup_idp{}", table_params.uuid));
+        }
+        ui.popup(
+            format!("##table_columns_popup_idp{}", table_params.uuid),
+            || {
+                let visible_count = table_params.headers.len() - table_state.hidden_columns.len();
+                for (column, header) in table_params.headers.iter().enumerate() {
+                    let mut visible = !table_state.hidden_columns.contains(&column);
+                    let label = if header.is_empty() {
+                        format!("Column {}", column + 1)
+                    } else {
+                        header.clone()
+                    };
+                    // the last visible column can't be hidden
+                    let _disabled = ui.begin_disabled(visible && visible_count == 1);
+                    if ui.checkbox(format!("{label}##table_column_{column}"), &mut visible) {
+                        if visible {
+                            table_state.hidden_columns.remove(&column);
+                        } else {
+                            table_state.hidden_columns.insert(column);
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn render_table_rows(
         ui: &Ui,
         pinned: &mut bool,
@@ -57,12 +136,37 @@ impl Context {
         bold_font: &Option<Font>,
         rendering_params: &RenderingParams,
         table_params: &TableParams,
+        visible_rows: &[usize],
+        visible_columns: &[usize],
+        merge_row_spans: bool,
+        table_states: &mut HashMap<String, TableState>,
+        highlight: &mut Option<TextHighlight>,
     ) {
         debug!("[render_table_rows]");
-        for row in &table_params.rows {
+        for row_index in visible_rows {
+            let row = &table_params.rows[*row_index];
             ui.table_next_row();
-            for cell in &row.cells {
+            for column in visible_columns {
                 ui.table_next_column();
+                let Some(cell) = row.cells.get(*column) else {
+                    continue;
+                };
+                // a spanned cell shows its content once, unless sorting or filtering
+                // separated the rows it spans
+                let render_copy = match cell.span {
+                    CellSpan::Origin => false,
+                    CellSpan::Row => !merge_row_spans,
+                    CellSpan::Column => !visible_columns.contains(&(column - 1)),
+                };
+                if !matches!(cell.span, CellSpan::Origin) && !render_copy {
+                    continue;
+                }
+                let mut no_highlight = None;
+                let cell_highlight = if render_copy {
+                    &mut no_highlight
+                } else {
+                    &mut *highlight
+                };
                 debug!("[render_table_rows] recursion");
                 Self::render_tokens(
                     ui,
@@ -75,6 +179,8 @@ impl Context {
                     bold_font,
                     rendering_params,
                     false,
+                    table_states,
+                    cell_highlight,
                 );
                 debug!("[render_table_rows] recursion end");
             }
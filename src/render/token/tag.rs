@@ -1,34 +1,126 @@
 use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::render::popup_data::price::{highest_sell_item_id, priced_item_ids};
 use crate::render::ui::{UiAction, UiLink};
+use crate::state::cache::caching_status::CachingStatus;
+use crate::state::cache::tag_preview::TagPreview;
+use crate::state::cache::{Cache, StoreInCache};
 use crate::state::context::Context;
+use crate::state::popup::find::TextHighlight;
 use crate::state::popup::tag_params::TagParams;
+use crate::state::popup::token::Token;
 use nexus::imgui::{MouseButton, Ui};
+use std::time::Duration;
+
+const TAG_PREVIEW_WIDTH: f32 = 350.0;
 
 impl Context {
+    #[allow(clippy::too_many_arguments)]
     pub fn render_tag(
         ui: &Ui,
         tag_params: &TagParams,
         pinned: &mut bool,
+        popup_id: u64,
         ui_actions: &mut Vec<UiAction>,
         current_indent: i32,
+        cache: &mut Cache,
         rendering_params: &RenderingParams,
+        highlight: &mut Option<TextHighlight>,
     ) {
         let href = tag_params.href.to_string();
         let title = tag_params.title.to_string();
+        let preview_delay = Duration::from_millis(rendering_params.tag_preview_delay_ms);
+        let mut show_preview = false;
         Self::render_words(
             ui,
             &tag_params.text,
             current_indent,
             rendering_params,
+            highlight,
             |ui: &Ui<'_>, word| {
                 ui.text_colored(rendering_params.link_color, word);
                 if ui.is_item_hovered() && ui.is_mouse_released(MouseButton::Left) && *pinned {
                     ui_actions.push(UiAction::Open(UiLink {
                         title: title.clone(),
                         href: href.clone(),
+                        popup_id,
                     }));
                 }
+                if ui.is_item_hovered()
+                    && rendering_params.show_tag_previews
+                    && cache.tag_previews.hover(&href, preview_delay)
+                {
+                    show_preview = true;
+                }
             },
         );
+        if show_preview {
+            Self::render_tag_preview(ui, &href, &title, cache, rendering_params);
+        }
+    }
+
+    fn render_tag_preview(
+        ui: &Ui,
+        href: &String,
+        title: &String,
+        cache: &mut Cache,
+        rendering_params: &RenderingParams,
+    ) {
+        let cached_preview = match cache.popup_data_map.get(href) {
+            Some(data) => Some(TagPreview::from_popup_data(data)),
+            None => {
+                let preview = cache.tag_previews.retrieve((href, title));
+                match preview.as_ref().map(|preview| &preview.caching_status) {
+                    Some(CachingStatus::Failed) => {
+                        ui.tooltip_text(format!("{title}\nPreview unavailable."));
+                        return;
+                    }
+                    _ => preview.and_then(|preview| preview.value().cloned()),
+                }
+            }
+        };
+        let Some(preview) = cached_preview else {
+            ui.tooltip_text(format!("{title}\nLoading.."));
+            return;
+        };
+        ui.tooltip(|| {
+            if let Some(Token::Image(icon_href, dimensions)) = &preview.icon {
+                Self::render_image(ui, icon_href, dimensions, cache);
+                ui.same_line();
+            }
+            ui.text(title);
+            if !preview.description.is_empty() {
+                let preview_params = RenderingParams {
+                    max_content_width: TAG_PREVIEW_WIDTH,
+                    ..rendering_params.clone()
+                };
+                Self::render_words(
+                    ui,
+                    &preview.description,
+                    -1,
+                    &preview_params,
+                    &mut None,
+                    |ui, word| ui.text(word),
+                );
+                ui.new_line();
+            }
+            let Some(item_ids) = priced_item_ids(title, &preview.item_ids) else {
+                return;
+            };
+            let Some(prices) = cache.prices.retrieve(item_ids) else {
+                return;
+            };
+            let Some(price) = highest_sell_item_id(&prices)
+                .and_then(|item_id| prices.get(&item_id))
+                .and_then(|price| price.value())
+            else {
+                return;
+            };
+            ui.text_disabled("Sell ");
+            ui.same_line();
+            Self::render_price(ui, price.lowest_sell, cache, rendering_params);
+            ui.text_disabled("Buy ");
+            ui.same_line();
+            Self::render_price(ui, price.highest_buy, cache, rendering_params);
+        });
     }
 }
@@ -2,9 +2,13 @@ use crate::configuration::popup::rendering_params::RenderingParams;
 use crate::render::ui::HIGHLIGHT_COLOR;
 use crate::state::context::Context;
 use crate::state::font::Font;
+use crate::state::popup::find::TextHighlight;
 use crate::state::popup::style::Style;
 use nexus::imgui::Ui;
 
+const MATCH_COLOR: [f32; 4] = [0.8, 0.6, 0.0, 0.45];
+const CURRENT_MATCH_COLOR: [f32; 4] = [1.0, 0.45, 0.0, 0.8];
+
 impl Context {
     pub fn render_text(
         ui: &Ui,
@@ -13,12 +17,14 @@ impl Context {
         current_indent: i32,
         rendering_params: &RenderingParams,
         bold_font: &Option<Font>,
+        highlight: &mut Option<TextHighlight>,
     ) {
         Self::render_words(
             ui,
             text,
             current_indent,
             rendering_params,
+            highlight,
             |ui, word| match style {
                 Style::Normal => ui.text(word),
                 Style::Bold => {
@@ -40,12 +46,23 @@ impl Context {
         text: &str,
         current_indent: i32,
         rendering_params: &RenderingParams,
+        highlight: &mut Option<TextHighlight>,
         mut render_word: F,
     ) where
         F: FnMut(&Ui, &str),
     {
+        let matches = highlight
+            .as_mut()
+            .map(|highlight| highlight.matches(text))
+            .unwrap_or_default();
         let mut first_word = true;
+        let mut word_start = 0;
         for word in text.split(" ") {
+            let word_end = word_start + word.len();
+            let word_match = matches
+                .iter()
+                .find(|(start, end, _)| *start < word_end && word_start < *end);
+            word_start = word_end + 1;
             if word.is_empty() {
                 continue;
             }
@@ -61,11 +78,39 @@ impl Context {
                 Self::handle_line_wrap(ui, current_indent, word_width, rendering_params);
                 format!(" {}", word)
             };
+            if let Some((_, _, is_current)) = word_match {
+                Self::render_match_background(ui, &final_word, *is_current, highlight);
+            }
             render_word(ui, final_word.as_str());
             ui.same_line();
         }
     }
 
+    fn render_match_background(
+        ui: &Ui,
+        word: &str,
+        is_current: bool,
+        highlight: &mut Option<TextHighlight>,
+    ) {
+        let pos = ui.cursor_screen_pos();
+        let size = ui.calc_text_size(word);
+        let color = if is_current {
+            CURRENT_MATCH_COLOR
+        } else {
+            MATCH_COLOR
+        };
+        ui.get_window_draw_list()
+            .add_rect(pos, [pos[0] + size[0], pos[1] + size[1]], color)
+            .filled(true)
+            .build();
+        if let Some(highlight) = highlight.as_mut().filter(|_| is_current) {
+            if highlight.scroll_to_current {
+                ui.set_scroll_here_y();
+                highlight.scroll_to_current = false;
+            }
+        }
+    }
+
     fn handle_line_wrap(
         ui: &Ui,
         current_indent: i32,
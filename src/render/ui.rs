@@ -7,6 +7,7 @@ use nexus::imgui::{
 };
 
 use crate::state::font::Font;
+use crate::state::lightbox::Lightbox;
 
 pub const CLOSE_BUTTON_SIZE: f32 = 25.0;
 pub const CLOSE_BUTTON_MARGIN_OUTER_X: f32 = 15.0;
@@ -20,12 +21,15 @@ pub enum UiAction {
     Close,
     Pin,
     Open(UiLink),
+    Compare(u64),
+    OpenLightbox(Lightbox),
 }
 
 #[derive(Clone, Debug)]
 pub struct UiLink {
     pub title: String,
     pub href: String,
+    pub popup_id: u64,
 }
 
 #[allow(dead_code)]
@@ -36,10 +40,12 @@ pub trait UiExtended {
     fn link<T: AsRef<str>>(&self, link: &str, text: T, color: [f32; 4], inline: bool);
     fn font_select(&self, label: impl AsRef<str>, current: &mut Option<Font>) -> bool;
     fn text_vert_centered(&self, text: impl AsRef<str>, height: &f32, disabled: &bool);
+    fn align_vert_centered(&self, text: impl AsRef<str>, height: &f32);
     fn text_or_disabled(&self, text: impl AsRef<str>, should_render_disabled: &bool);
     fn close_button(&self, text: impl AsRef<str>, x_pos_limit: &f32) -> bool;
     fn not_in_view(&self, height: &f32) -> bool;
     fn set_next_window_pos(&self, pos: [f32; 2]);
+    fn ui_scale(&self) -> f32;
 }
 
 impl UiExtended for Ui<'_> {
@@ -130,13 +136,17 @@ impl UiExtended for Ui<'_> {
     }
 
     fn text_vert_centered(&self, text: impl AsRef<str>, height: &f32, disabled: &bool) {
+        self.align_vert_centered(&text, height);
+        self.text_or_disabled(text, disabled);
+    }
+
+    fn align_vert_centered(&self, text: impl AsRef<str>, height: &f32) {
         let text_height = self.calc_text_size(&text)[1];
         let cur_pos = self.cursor_pos();
         self.set_cursor_pos([
             cur_pos[0],
             cur_pos[1] + (height / 2.0) - (text_height / 2.0),
         ]);
-        self.text_or_disabled(text, disabled);
     }
 
     fn text_or_disabled(&self, text: impl AsRef<str>, should_render_disabled: &bool) {
@@ -197,4 +207,10 @@ impl UiExtended for Ui<'_> {
     fn set_next_window_pos(&self, pos: [f32; 2]) {
         unsafe { sys::igSetNextWindowPos(ImVec2::new(pos[0], pos[1]), 0, ImVec2::zero()) }
     }
+
+    // Pixels per ui unit, so textures stay sharp on scaled interfaces.
+    fn ui_scale(&self) -> f32 {
+        let io = self.io();
+        io.font_global_scale * io.display_framebuffer_scale[0]
+    }
 }
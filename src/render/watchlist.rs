@@ -0,0 +1,119 @@
+use crate::configuration::{read_config, write_config};
+use crate::state::cache::StoreInCache;
+use crate::state::context::Context;
+use crate::state::threads::link::open_link_thread;
+use log::debug;
+use nexus::imgui::{Condition, TableFlags, Ui, Window};
+
+const WATCHLIST_WINDOW_SIZE: [f32; 2] = [560.0, 300.0];
+const THRESHOLD_INPUT_WIDTH: f32 = 90.0;
+
+impl Context {
+    pub fn render_watchlist(&mut self, ui: &Ui) {
+        if !self.ui.watchlist_opened {
+            return;
+        }
+        debug!("[render_watchlist]");
+        let rendering_params = read_config().rendering_params.clone();
+        let mut entries = read_config().watchlist.entries.clone();
+        let mut opened = self.ui.watchlist_opened;
+        let mut changed = false;
+        let mut removed_item_id = None;
+        Window::new("Price watchlist##idp")
+            .size(WATCHLIST_WINDOW_SIZE, Condition::FirstUseEver)
+            .opened(&mut opened)
+            .build(ui, || {
+                if entries.is_empty() {
+                    ui.text_disabled(
+                        "Add items using \"More..\" > \"Add to watchlist\" in a popup.",
+                    );
+                    return;
+                }
+                let item_ids = entries.iter().map(|entry| entry.item_id).collect();
+                let prices = self.cache.prices.retrieve(item_ids).unwrap_or_default();
+                let Some(_t) = ui.begin_table_with_flags(
+                    "watchlist##idp",
+                    6,
+                    TableFlags::RESIZABLE | TableFlags::NO_SAVED_SETTINGS | TableFlags::ROW_BG,
+                ) else {
+                    return;
+                };
+                for header in ["Item", "Sell", "Buy", "Sell alert", "Buy alert", ""] {
+                    ui.table_setup_column(header);
+                }
+                ui.table_headers_row();
+                for entry in entries.iter_mut() {
+                    ui.table_next_row();
+                    ui.table_next_column();
+                    ui.text_colored(rendering_params.link_color, &entry.name);
+                    if ui.is_item_clicked() {
+                        open_link_thread(
+                            format!("/wiki/{}", entry.name.replace(" ", "_")),
+                            entry.name.clone(),
+                        );
+                    }
+                    let price = prices
+                        .get(&entry.item_id)
+                        .and_then(|cached_price| cached_price.value())
+                        .cloned();
+                    ui.table_next_column();
+                    if let Some(price) = &price {
+                        Self::render_price(
+                            ui,
+                            price.lowest_sell,
+                            &mut self.cache,
+                            &rendering_params,
+                        );
+                    }
+                    ui.table_next_column();
+                    if let Some(price) = &price {
+                        Self::render_price(
+                            ui,
+                            price.highest_buy,
+                            &mut self.cache,
+                            &rendering_params,
+                        );
+                    }
+                    ui.table_next_column();
+                    changed |= render_threshold_input(
+                        ui,
+                        format!("##wl_sell_idp{}", entry.item_id),
+                        &mut entry.sell_threshold,
+                    );
+                    ui.table_next_column();
+                    changed |= render_threshold_input(
+                        ui,
+                        format!("##wl_buy_idp{}", entry.item_id),
+                        &mut entry.buy_threshold,
+                    );
+                    ui.table_next_column();
+                    if ui.small_button(format!("Remove##wl_idp{}", entry.item_id)) {
+                        removed_item_id = Some(entry.item_id);
+                    }
+                }
+            });
+        self.ui.watchlist_opened = opened;
+        if let Some(item_id) = removed_item_id {
+            write_config().watchlist.remove(item_id);
+        } else if changed {
+            write_config().watchlist.entries = entries;
+        }
+    }
+}
+
+fn render_threshold_input(ui: &Ui, label: String, threshold: &mut Option<u32>) -> bool {
+    let mut value = threshold.unwrap_or_default() as i32;
+    ui.set_next_item_width(THRESHOLD_INPUT_WIDTH);
+    let changed = ui
+        .input_int(label, &mut value)
+        .step(100)
+        .step_fast(10000)
+        .build();
+    if ui.is_item_hovered() {
+        ui.tooltip_text("Price in copper, 0 disables the alert");
+    }
+    if changed {
+        *threshold = u32::try_from(value).ok().filter(|value| *value > 0);
+    }
+    changed
+}
@@ -0,0 +1,341 @@
+use crate::api::api_error::ApiError;
+use crate::api::gw2::build::build_api_response::NamedApiResponse;
+use crate::api::gw2::build::{get_legends, get_pets, get_skills, get_specializations, get_traits};
+use crate::state::cache::profession::retrieve_professions;
+use crate::state::popup::dimensions::Dimensions;
+use crate::state::popup::popup_data::PopupData;
+use crate::state::popup::style::Style;
+use crate::state::popup::tag_params::TagParams;
+use crate::state::popup::token::Token;
+use crate::state::popup::Popup;
+use indexmap::IndexMap;
+use log::debug;
+use std::collections::HashMap;
+
+const BUILD_TEMPLATE_MIN_LENGTH: usize = 44;
+const SPECIALIZATIONS_OFFSET: usize = 2;
+const SKILLS_OFFSET: usize = 8;
+const PROFESSION_SPECIFIC_OFFSET: usize = 28;
+const SPECIALIZATION_COUNT: usize = 3;
+const SKILL_SLOT_COUNT: usize = 5;
+const TRAIT_TIER_COUNT: usize = 3;
+const RANGER_CODE: u8 = 4;
+const REVENANT_CODE: u8 = 9;
+const BUILD_HREF_MARKER: &str = "#build=";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpecializationChoice {
+    pub id: u8,
+    // adept, master, grandmaster; 0 = none, 1 = top, 2 = middle, 3 = bottom
+    pub traits: [u8; TRAIT_TIER_COUNT],
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuildTemplate {
+    pub profession_code: u8,
+    pub specializations: Vec<SpecializationChoice>,
+    pub terrestrial_palette_ids: Vec<u16>,
+    pub aquatic_palette_ids: Vec<u16>,
+    pub terrestrial_pets: Vec<u8>,
+    pub aquatic_pets: Vec<u8>,
+    pub terrestrial_legends: Vec<u8>,
+    pub aquatic_legends: Vec<u8>,
+}
+
+impl BuildTemplate {
+    pub fn decode(bytes: &[u8]) -> Result<Self, &'static str> {
+        if bytes.len() < BUILD_TEMPLATE_MIN_LENGTH {
+            return Err("Could not decode chat code: build template too short");
+        }
+        let profession_code = bytes[1];
+        let specializations = bytes
+            [SPECIALIZATIONS_OFFSET..SPECIALIZATIONS_OFFSET + SPECIALIZATION_COUNT * 2]
+            .chunks(2)
+            .filter(|spec| spec[0] != 0)
+            .map(|spec| SpecializationChoice {
+                id: spec[0],
+                traits: [spec[1] & 0b11, (spec[1] >> 2) & 0b11, (spec[1] >> 4) & 0b11],
+            })
+            .collect();
+        // terrestrial and aquatic palette ids are interleaved per slot
+        let palette_ids: Vec<u16> = bytes[SKILLS_OFFSET..SKILLS_OFFSET + SKILL_SLOT_COUNT * 4]
+            .chunks(2)
+            .map(|id| u16::from_le_bytes([id[0], id[1]]))
+            .collect();
+        let profession_specific =
+            &bytes[PROFESSION_SPECIFIC_OFFSET..PROFESSION_SPECIFIC_OFFSET + 4];
+        let (terrestrial_specific, aquatic_specific) = (
+            non_zero(&profession_specific[..2]),
+            non_zero(&profession_specific[2..]),
+        );
+        let is_ranger = profession_code == RANGER_CODE;
+        let is_revenant = profession_code == REVENANT_CODE;
+        Ok(Self {
+            profession_code,
+            specializations,
+            terrestrial_palette_ids: palette_ids.iter().step_by(2).copied().collect(),
+            aquatic_palette_ids: palette_ids.iter().skip(1).step_by(2).copied().collect(),
+            terrestrial_pets: if is_ranger {
+                terrestrial_specific.clone()
+            } else {
+                vec![]
+            },
+            aquatic_pets: if is_ranger {
+                aquatic_specific.clone()
+            } else {
+                vec![]
+            },
+            terrestrial_legends: if is_revenant {
+                terrestrial_specific
+            } else {
+                vec![]
+            },
+            aquatic_legends: if is_revenant {
+                aquatic_specific
+            } else {
+                vec![]
+            },
+        })
+    }
+}
+
+fn non_zero(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().filter(|byte| **byte != 0).copied().collect()
+}
+
+// The profession article stays the wiki target, the chat code keeps each build apart from it.
+pub fn build_template_href(profession_name: &str, chat_code: &str) -> String {
+    format!(
+        "{}{BUILD_HREF_MARKER}{chat_code}",
+        name_to_href(profession_name)
+    )
+}
+
+pub fn build_chat_code(href: &str) -> Option<&str> {
+    href.split_once(BUILD_HREF_MARKER)
+        .map(|(_, chat_code)| chat_code)
+}
+
+pub fn prepare_build_template_popup(
+    build_template: &BuildTemplate,
+    chat_code: &str,
+) -> Option<Popup> {
+    debug!(
+        "[prepare_build_template_popup] profession code {}",
+        build_template.profession_code
+    );
+    let professions = retrieve_professions()?;
+    let Some(profession) = professions.get(&build_template.profession_code) else {
+        debug!("[prepare_build_template_popup] unknown profession");
+        return None;
+    };
+    let mut data = PopupData {
+        title: format!("{} build", profession.name),
+        href: build_template_href(&profession.name, chat_code),
+        item_icon: Some(Token::Image(
+            profession.icon.clone(),
+            Some(Dimensions::medium()),
+        )),
+        description: vec![Token::Tag(tag_params(&profession.name))],
+        ..PopupData::default()
+    };
+
+    let specializations_tokens = specializations_tokens(build_template)
+        .inspect_err(|e| e.log())
+        .ok()?;
+    data.sections
+        .insert("Specializations".to_string(), specializations_tokens);
+
+    let skills_tokens = skills_tokens(build_template, &profession.skills_by_palette)
+        .inspect_err(|e| e.log())
+        .ok()?;
+    data.sections.insert("Skills".to_string(), skills_tokens);
+
+    if !build_template.terrestrial_pets.is_empty() || !build_template.aquatic_pets.is_empty() {
+        let pets_tokens = pets_tokens(build_template).inspect_err(|e| e.log()).ok()?;
+        data.sections.insert("Pets".to_string(), pets_tokens);
+    }
+    if !build_template.terrestrial_legends.is_empty() || !build_template.aquatic_legends.is_empty()
+    {
+        let legends_tokens = legends_tokens(build_template)
+            .inspect_err(|e| e.log())
+            .ok()?;
+        data.sections.insert("Legends".to_string(), legends_tokens);
+    }
+    Some(Popup::new(data))
+}
+
+fn specializations_tokens(build_template: &BuildTemplate) -> Result<Vec<Token>, ApiError> {
+    let specialization_ids: Vec<u32> = build_template
+        .specializations
+        .iter()
+        .map(|spec| spec.id as u32)
+        .collect();
+    if specialization_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let specializations = get_specializations(&specialization_ids)?;
+    let mut chosen_traits = IndexMap::new();
+    for choice in &build_template.specializations {
+        let Some(specialization) = specializations.iter().find(|s| s.id == choice.id as u32) else {
+            continue;
+        };
+        let trait_ids: Vec<u32> = choice
+            .traits
+            .iter()
+            .enumerate()
+            .filter(|(_, choice)| **choice != 0)
+            .filter_map(|(tier, choice)| {
+                specialization
+                    .major_traits
+                    .get(tier * TRAIT_TIER_COUNT + *choice as usize - 1)
+                    .copied()
+            })
+            .collect();
+        chosen_traits.insert(specialization.id, trait_ids);
+    }
+    let all_trait_ids: Vec<u32> = chosen_traits.values().flatten().copied().collect();
+    let traits = if all_trait_ids.is_empty() {
+        HashMap::new()
+    } else {
+        by_id(get_traits(&all_trait_ids)?)
+    };
+
+    let mut tokens = vec![];
+    for (specialization_id, trait_ids) in chosen_traits {
+        let Some(specialization) = specializations.iter().find(|s| s.id == specialization_id)
+        else {
+            continue;
+        };
+        tokens.push(Token::Image(
+            specialization.icon.clone(),
+            Some(Dimensions::small()),
+        ));
+        tokens.push(Token::Tag(tag_params(&specialization.name)));
+        tokens.push(Token::Spacing);
+        for trait_id in trait_ids {
+            if let Some(trait_data) = traits.get(&trait_id) {
+                push_named_entry(&mut tokens, trait_data);
+            }
+        }
+        tokens.push(Token::Spacing);
+    }
+    Ok(tokens)
+}
+
+fn skills_tokens(
+    build_template: &BuildTemplate,
+    skills_by_palette: &HashMap<u16, u32>,
+) -> Result<Vec<Token>, ApiError> {
+    let to_skill_ids = |palette_ids: &Vec<u16>| -> Vec<u32> {
+        palette_ids
+            .iter()
+            .filter_map(|palette_id| skills_by_palette.get(palette_id))
+            .copied()
+            .collect()
+    };
+    let terrestrial_skill_ids = to_skill_ids(&build_template.terrestrial_palette_ids);
+    let aquatic_skill_ids = to_skill_ids(&build_template.aquatic_palette_ids);
+    let all_skill_ids: Vec<u32> = terrestrial_skill_ids
+        .iter()
+        .chain(aquatic_skill_ids.iter())
+        .copied()
+        .collect();
+    if all_skill_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let skills = by_id(get_skills(&all_skill_ids)?);
+    let mut tokens = vec![];
+    push_named_group(&mut tokens, "Terrestrial", &terrestrial_skill_ids, &skills);
+    push_named_group(&mut tokens, "Aquatic", &aquatic_skill_ids, &skills);
+    Ok(tokens)
+}
+
+fn pets_tokens(build_template: &BuildTemplate) -> Result<Vec<Token>, ApiError> {
+    let to_ids = |pets: &Vec<u8>| -> Vec<u32> { pets.iter().map(|id| *id as u32).collect() };
+    let terrestrial_pet_ids = to_ids(&build_template.terrestrial_pets);
+    let aquatic_pet_ids = to_ids(&build_template.aquatic_pets);
+    let all_pet_ids: Vec<u32> = terrestrial_pet_ids
+        .iter()
+        .chain(aquatic_pet_ids.iter())
+        .copied()
+        .collect();
+    let pets = by_id(get_pets(&all_pet_ids)?);
+    let mut tokens = vec![];
+    push_named_group(&mut tokens, "Terrestrial", &terrestrial_pet_ids, &pets);
+    push_named_group(&mut tokens, "Aquatic", &aquatic_pet_ids, &pets);
+    Ok(tokens)
+}
+
+fn legends_tokens(build_template: &BuildTemplate) -> Result<Vec<Token>, ApiError> {
+    // legends have no names of their own, their swap skill is named after them
+    let swap_skill_by_code: HashMap<u8, u32> = get_legends()?
+        .into_iter()
+        .map(|legend| (legend.code, legend.swap))
+        .collect();
+    let to_skill_ids = |legends: &Vec<u8>| -> Vec<u32> {
+        legends
+            .iter()
+            .filter_map(|code| swap_skill_by_code.get(code))
+            .copied()
+            .collect()
+    };
+    let terrestrial_skill_ids = to_skill_ids(&build_template.terrestrial_legends);
+    let aquatic_skill_ids = to_skill_ids(&build_template.aquatic_legends);
+    let all_skill_ids: Vec<u32> = terrestrial_skill_ids
+        .iter()
+        .chain(aquatic_skill_ids.iter())
+        .copied()
+        .collect();
+    if all_skill_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let skills = by_id(get_skills(&all_skill_ids)?);
+    let mut tokens = vec![];
+    push_named_group(&mut tokens, "Terrestrial", &terrestrial_skill_ids, &skills);
+    push_named_group(&mut tokens, "Aquatic", &aquatic_skill_ids, &skills);
+    Ok(tokens)
+}
+
+fn push_named_group(
+    tokens: &mut Vec<Token>,
+    label: &str,
+    ids: &[u32],
+    entries: &HashMap<u32, NamedApiResponse>,
+) {
+    if ids.is_empty() {
+        return;
+    }
+    tokens.push(Token::Text(format!("{label}:"), Style::Bold));
+    tokens.push(Token::Spacing);
+    for id in ids {
+        if let Some(entry) = entries.get(id) {
+            push_named_entry(tokens, entry);
+        }
+    }
+    tokens.push(Token::Spacing);
+}
+
+fn push_named_entry(tokens: &mut Vec<Token>, entry: &NamedApiResponse) {
+    if let Some(icon) = &entry.icon {
+        tokens.push(Token::Image(icon.clone(), Some(Dimensions::small())));
+    }
+    tokens.push(Token::Tag(tag_params(&entry.name)));
+    tokens.push(Token::Spacing);
+}
+
+fn by_id(entries: Vec<NamedApiResponse>) -> HashMap<u32, NamedApiResponse> {
+    entries.into_iter().map(|entry| (entry.id, entry)).collect()
+}
+
+fn tag_params(name: &str) -> TagParams {
+    TagParams {
+        href: name_to_href(name),
+        text: name.to_string(),
+        title: name.to_string(),
+    }
+}
+
+fn name_to_href(name: &str) -> String {
+    format!("/wiki/{}", name.replace(" ", "_"))
+}
@@ -0,0 +1,78 @@
+use crate::service::build_template::BuildTemplate;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+const ITEM_LINK_TYPE: u8 = 0x02;
+const MAP_LINK_TYPE: u8 = 0x04;
+const PVP_GAME_LINK_TYPE: u8 = 0x05;
+const SKILL_LINK_TYPE: u8 = 0x06;
+const TRAIT_LINK_TYPE: u8 = 0x07;
+const RECIPE_LINK_TYPE: u8 = 0x09;
+const SKIN_LINK_TYPE: u8 = 0x0A;
+const OUTFIT_LINK_TYPE: u8 = 0x0B;
+const BUILD_TEMPLATE_LINK_TYPE: u8 = 0x0D;
+const ITEM_LINK_MIN_LENGTH: usize = 6;
+const ID_LINK_MIN_LENGTH: usize = 5;
+
+lazy_static! {
+    static ref CHAT_CODE_REGEX: Regex = Regex::new(r"\[&([A-Za-z0-9+/=]+)\]").unwrap();
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChatLink {
+    Item { id: u32, quantity: u8 },
+    Map(u32),
+    PvpGame(u32),
+    Skill(u32),
+    Trait(u32),
+    Recipe(u32),
+    Skin(u32),
+    Outfit(u32),
+    BuildTemplate(BuildTemplate),
+}
+
+pub fn extract_chat_code(chat_message: &str) -> Option<String> {
+    CHAT_CODE_REGEX
+        .find(chat_message)
+        .map(|chat_code| chat_code.as_str().to_string())
+}
+
+pub fn decode_chat_code(chat_code: &str) -> Result<ChatLink, &'static str> {
+    let Some(captures) = CHAT_CODE_REGEX.captures(chat_code) else {
+        return Err("Could not decode chat code: invalid format");
+    };
+    let bytes = STANDARD
+        .decode(&captures[1])
+        .map_err(|_| "Could not decode chat code: invalid base64")?;
+    let Some(link_type) = bytes.first() else {
+        return Err("Could not decode chat code: empty chat code");
+    };
+    if *link_type == ITEM_LINK_TYPE {
+        if bytes.len() < ITEM_LINK_MIN_LENGTH {
+            return Err("Could not decode chat code: item link too short");
+        }
+        return Ok(ChatLink::Item {
+            id: u32::from_le_bytes([bytes[2], bytes[3], bytes[4], 0]),
+            quantity: bytes[1],
+        });
+    }
+    if *link_type == BUILD_TEMPLATE_LINK_TYPE {
+        return BuildTemplate::decode(&bytes).map(ChatLink::BuildTemplate);
+    }
+    if bytes.len() < ID_LINK_MIN_LENGTH {
+        return Err("Could not decode chat code: link too short");
+    }
+    let id = u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+    match *link_type {
+        MAP_LINK_TYPE => Ok(ChatLink::Map(id)),
+        PVP_GAME_LINK_TYPE => Ok(ChatLink::PvpGame(id)),
+        SKILL_LINK_TYPE => Ok(ChatLink::Skill(id)),
+        TRAIT_LINK_TYPE => Ok(ChatLink::Trait(id)),
+        RECIPE_LINK_TYPE => Ok(ChatLink::Recipe(id)),
+        SKIN_LINK_TYPE => Ok(ChatLink::Skin(id)),
+        OUTFIT_LINK_TYPE => Ok(ChatLink::Outfit(id)),
+        _ => Err("Could not decode chat code: unsupported link type"),
+    }
+}
@@ -0,0 +1,231 @@
+use crate::service::popup::process_text;
+use crate::state::popup::container_contents::{ContainerContents, ContentsKind, ContentsOutcome};
+use crate::state::popup::Popup;
+use log::debug;
+use scraper::{ElementRef, Html, Node, Selector};
+
+const ITEM_HEADERS: [&str; 3] = ["item", "name", "reward"];
+const QUANTITY_HEADERS: [&str; 3] = ["quantity", "qty", "amount"];
+const PROBABILITY_HEADERS: [&str; 4] = ["chance", "probability", "rate", "drop"];
+
+pub fn fill_container_contents(document: &Html, popup: &mut Popup) {
+    debug!("[fill_container_contents]");
+    let heading_selector =
+        Selector::parse("div.mw-parser-output > h2, div.mw-parser-output > h3").unwrap();
+    let headline_selector = Selector::parse(".mw-headline").unwrap();
+    for heading in document.select(&heading_selector) {
+        let Some(kind) = heading
+            .select(&headline_selector)
+            .next()
+            .map(|headline| headline.text().collect::<String>())
+            .and_then(|headline| contents_kind(&headline))
+        else {
+            continue;
+        };
+        let heading_name = heading.value().name();
+        let mut outcomes = vec![];
+        let mut next = heading.next_sibling();
+        while let Some(node) = next {
+            next = node.next_sibling();
+            let Some(element) = ElementRef::wrap(node) else {
+                continue;
+            };
+            let tag_name = element.value().name();
+            if tag_name == "h2" || tag_name == heading_name {
+                break;
+            }
+            parse_outcomes(element, &mut outcomes);
+        }
+        if !outcomes.is_empty() {
+            popup.data.contents = Some(ContainerContents { kind, outcomes });
+            return;
+        }
+    }
+}
+
+fn contents_kind(headline: &str) -> Option<ContentsKind> {
+    let headline = headline.to_lowercase();
+    if headline.contains("salvage") {
+        Some(ContentsKind::Salvage)
+    } else if headline.contains("contents") {
+        Some(ContentsKind::Contents)
+    } else {
+        None
+    }
+}
+
+fn parse_outcomes(element: ElementRef, outcomes: &mut Vec<ContentsOutcome>) {
+    match element.value().name() {
+        "table" => parse_table_outcomes(element, outcomes),
+        "ul" => parse_list_outcomes(element, outcomes),
+        _ => {
+            let nested_selector = Selector::parse("table, ul").unwrap();
+            for nested in element.select(&nested_selector) {
+                // nested lists are handled by their parent list
+                if nested
+                    .ancestors()
+                    .filter_map(ElementRef::wrap)
+                    .any(|ancestor| matches!(ancestor.value().name(), "ul" | "table"))
+                {
+                    continue;
+                }
+                parse_outcomes(nested, outcomes);
+            }
+        }
+    }
+}
+
+fn parse_table_outcomes(table: ElementRef, outcomes: &mut Vec<ContentsOutcome>) {
+    let row_selector = Selector::parse("tr").unwrap();
+    let header_selector = Selector::parse("th").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
+    let headers: Vec<String> = table
+        .select(&row_selector)
+        .next()
+        .map(|row| {
+            row.select(&header_selector)
+                .map(|header| process_text(&header.text().collect::<String>()).to_lowercase())
+                .collect()
+        })
+        .unwrap_or_default();
+    let column = |names: &[&str]| {
+        headers
+            .iter()
+            .position(|header| names.iter().any(|name| header.contains(name)))
+    };
+    let item_column = column(&ITEM_HEADERS).unwrap_or(0);
+    let quantity_column = column(&QUANTITY_HEADERS);
+    let probability_column = column(&PROBABILITY_HEADERS);
+
+    for row in table.select(&row_selector) {
+        let cells: Vec<ElementRef> = row.select(&cell_selector).collect();
+        let Some(item_cell) = cells.get(item_column) else {
+            continue;
+        };
+        let cell_text = |index: Option<usize>| {
+            index
+                .and_then(|index| cells.get(index))
+                .map(|cell| process_text(&cell.text().collect::<String>()))
+        };
+        let quantity = cell_text(quantity_column)
+            .or_else(|| cell_text(Some(item_column)))
+            .and_then(|text| parse_quantity_range(&text));
+        let probability = cell_text(probability_column).and_then(|text| parse_probability(&text));
+        push_outcome(*item_cell, quantity, probability, outcomes);
+    }
+}
+
+fn parse_list_outcomes(list: ElementRef, outcomes: &mut Vec<ContentsOutcome>) {
+    for item in list.children().filter_map(ElementRef::wrap) {
+        if item.value().name() != "li" {
+            continue;
+        }
+        let own_text = process_text(
+            &item
+                .children()
+                .filter(|child| {
+                    !ElementRef::wrap(*child)
+                        .is_some_and(|element| matches!(element.value().name(), "ul" | "dl"))
+                })
+                .flat_map(|child| child.descendants())
+                .filter_map(|node| match node.value() {
+                    Node::Text(text) => Some(text.to_string()),
+                    _ => None,
+                })
+                .collect::<String>(),
+        );
+        push_outcome(
+            item,
+            parse_quantity_range(&own_text),
+            parse_probability(&own_text),
+            outcomes,
+        );
+        for nested in item.children().filter_map(ElementRef::wrap) {
+            if nested.value().name() == "ul" {
+                parse_list_outcomes(nested, outcomes);
+            }
+        }
+    }
+}
+
+fn push_outcome(
+    element: ElementRef,
+    quantity: Option<(f32, f32)>,
+    probability: Option<f32>,
+    outcomes: &mut Vec<ContentsOutcome>,
+) {
+    let link_selector = Selector::parse("a[title]:not(.external, .extiw, .image)").unwrap();
+    let Some((href, name)) = element
+        .select(&link_selector)
+        .filter(|link| {
+            !link
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .take_while(|ancestor| ancestor.id() != element.id())
+                .any(|ancestor| ancestor.value().name() == "ul")
+        })
+        .find(|link| !process_text(&link.text().collect::<String>()).is_empty())
+        .and_then(|link| Some((link.value().attr("href")?, link.value().attr("title")?)))
+    else {
+        return;
+    };
+    if !href.starts_with("/wiki/") || href.contains(':') {
+        return;
+    }
+    let (min_quantity, max_quantity) = quantity.unwrap_or((1.0, 1.0));
+    outcomes.push(ContentsOutcome {
+        name: process_text(name),
+        href: href.split('#').next().unwrap_or(href).to_string(),
+        min_quantity,
+        max_quantity,
+        probability,
+    });
+}
+
+// "3", "1-3", "1–3 Mystic Coin", "1,000"
+fn parse_quantity_range(text: &str) -> Option<(f32, f32)> {
+    let normalized = text.replace(',', "").replace(['–', '—'], "-");
+    let prefix: String = normalized
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | ' '))
+        .collect();
+    let mut bounds = prefix
+        .split('-')
+        .map(str::trim)
+        .filter(|bound| !bound.is_empty())
+        .map(|bound| bound.parse::<f32>());
+    let min = bounds.next()?.ok()?;
+    let max = match bounds.next() {
+        Some(Ok(max)) if max >= min => max,
+        _ => min,
+    };
+    Some((min, max))
+}
+
+// "25%", "0.5 %", "1 in 20"
+fn parse_probability(text: &str) -> Option<f32> {
+    let text = text.replace(',', "");
+    if let Some(end) = text.find('%') {
+        let digits: Vec<char> = text[..end]
+            .trim_end()
+            .chars()
+            .rev()
+            .take_while(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
+        let percentage = digits.into_iter().rev().collect::<String>();
+        return percentage
+            .parse::<f32>()
+            .ok()
+            .filter(|percentage| *percentage <= 100.0)
+            .map(|percentage| percentage / 100.0);
+    }
+    let (numerator, denominator) = text.split_once(" in ")?;
+    let numerator = numerator.split_whitespace().last()?.parse::<f32>().ok()?;
+    let denominator = denominator.split_whitespace().next()?.parse::<f32>().ok()?;
+    if denominator > 0.0 && numerator <= denominator {
+        Some(numerator / denominator)
+    } else {
+        None
+    }
+}
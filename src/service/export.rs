@@ -0,0 +1,205 @@
+use crate::api::gw2_wiki::href_to_wiki_url;
+use crate::state::context::write_context;
+use crate::state::popup::find::GENERAL_TAB;
+use crate::state::popup::style::Style;
+use crate::state::popup::table_params::{CellSpan, TableCell, TableParams};
+use crate::state::popup::token::Token;
+use crate::state::popup::Popup;
+use crate::threads::lock_threads;
+use nexus::alert::send_alert;
+use std::thread;
+
+const COIN_SUFFIXES: [(&str, &str); 3] = [
+    ("gold_coin", "g"),
+    ("silver_coin", "s"),
+    ("copper_coin", "c"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Markdown,
+    Text,
+}
+
+// prices are the (lowest sell, highest buy) shown in the popup
+pub fn copy_popup_content(popup: &Popup, prices: Option<(u32, u32)>, format: ExportFormat) {
+    let content = popup_to_text(popup, prices, format);
+    lock_threads().push(thread::spawn(move || {
+        let _ = write_context().clipboard.set_text(content.as_str());
+        match format {
+            ExportFormat::Markdown => send_alert("Popup copied to clipboard as Markdown."),
+            ExportFormat::Text => send_alert("Popup copied to clipboard as text."),
+        }
+    }));
+}
+
+pub fn popup_to_text(popup: &Popup, prices: Option<(u32, u32)>, format: ExportFormat) -> String {
+    let data = &popup.data;
+    let url = href_to_wiki_url(data.redirection_href.as_ref().unwrap_or(&data.href));
+    let mut blocks = vec![match format {
+        ExportFormat::Markdown => format!("# [{}]({url})", escape_markdown(&data.title)),
+        ExportFormat::Text => format!("{}\n{url}", data.title),
+    }];
+    if let Some((sell, buy)) = prices {
+        blocks.push(match format {
+            ExportFormat::Markdown => format!(
+                "**Sell:** {} | **Buy:** {}",
+                format_coins(sell),
+                format_coins(buy)
+            ),
+            ExportFormat::Text => {
+                format!("Sell: {} | Buy: {}", format_coins(sell), format_coins(buy))
+            }
+        });
+    }
+    let description = tokens_to_text(&data.description, format);
+    if !description.is_empty() {
+        blocks.push(description);
+    }
+    let selected_section = popup
+        .state
+        .selected_tab
+        .as_ref()
+        .filter(|tab| tab.as_str() != GENERAL_TAB)
+        .and_then(|tab| Some((tab, data.sections.get(tab)?)));
+    if let Some((tab, tokens)) = selected_section {
+        blocks.push(match format {
+            ExportFormat::Markdown => format!("## {}", escape_markdown(tab)),
+            ExportFormat::Text => format!("{tab}\n{}", "-".repeat(tab.chars().count())),
+        });
+        blocks.push(tokens_to_text(tokens, format));
+    }
+    blocks
+        .into_iter()
+        .filter(|block| !block.trim().is_empty())
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+fn format_coins(price: u32) -> String {
+    let (gold, silver, copper) = (price / 10000, (price % 10000) / 100, price % 100);
+    match (gold, silver) {
+        (0, 0) => format!("{copper}c"),
+        (0, _) => format!("{silver}s {copper}c"),
+        _ => format!("{gold}g {silver}s {copper}c"),
+    }
+}
+
+fn tokens_to_text(tokens: &[Token], format: ExportFormat) -> String {
+    let mut lines: Vec<String> = vec![];
+    let mut line = String::new();
+    let mut indent = 0;
+    for token in tokens {
+        match token {
+            Token::Text(text, style) => {
+                let text = match (format, style) {
+                    (ExportFormat::Markdown, Style::Bold) => {
+                        format!("**{}**", escape_markdown(text))
+                    }
+                    (ExportFormat::Markdown, _) => escape_markdown(text),
+                    (ExportFormat::Text, _) => text.clone(),
+                };
+                push_fragment(&mut line, &text);
+            }
+            Token::Tag(tag_params) => {
+                let url = href_to_wiki_url(&tag_params.href);
+                let link = match format {
+                    ExportFormat::Markdown => {
+                        format!("[{}]({url})", escape_markdown(&tag_params.text))
+                    }
+                    ExportFormat::Text => format!("{} ({url})", tag_params.text),
+                };
+                push_fragment(&mut line, &link);
+            }
+            Token::Image(href, _) => {
+                let href = href.to_lowercase();
+                if let Some((_, suffix)) =
+                    COIN_SUFFIXES.iter().find(|(name, _)| href.contains(name))
+                {
+                    line.push_str(suffix);
+                }
+            }
+            Token::Indent(depth) => indent = (*depth).max(0) as usize,
+            Token::ListElement => {
+                finish_line(&mut lines, &mut line);
+                line = format!("{}- ", "  ".repeat(indent));
+            }
+            Token::Spacing => finish_line(&mut lines, &mut line),
+            Token::Table(table_params) => {
+                finish_line(&mut lines, &mut line);
+                lines.push(String::new());
+                lines.extend(table_to_lines(table_params, format));
+                lines.push(String::new());
+            }
+        }
+    }
+    finish_line(&mut lines, &mut line);
+    let mut result = lines.join("\n");
+    while result.contains("\n\n\n") {
+        result = result.replace("\n\n\n", "\n\n");
+    }
+    result.trim().to_string()
+}
+
+fn push_fragment(line: &mut String, fragment: &str) {
+    let attaches_to_previous = fragment.starts_with(['.', ',', ';', ':', ')', '!', '?']);
+    if !line.is_empty() && !line.ends_with([' ', '(']) && !attaches_to_previous {
+        line.push(' ');
+    }
+    line.push_str(fragment);
+}
+
+fn finish_line(lines: &mut Vec<String>, line: &mut String) {
+    let finished = line.trim_end();
+    if !finished.is_empty() && finished.trim() != "-" {
+        lines.push(finished.to_string());
+    }
+    line.clear();
+}
+
+fn table_to_lines(table_params: &TableParams, format: ExportFormat) -> Vec<String> {
+    let cell_text = |cell: &TableCell| match cell.span {
+        CellSpan::Column => String::new(),
+        // markdown text is already escaped by tokens_to_text
+        _ => tokens_to_text(&cell.tokens, format).replace('\n', " "),
+    };
+    let row_line = |cells: Vec<String>| match format {
+        ExportFormat::Markdown => format!("| {} |", cells.join(" | ")),
+        ExportFormat::Text => cells.join(" | "),
+    };
+    let column_count = table_params.headers.len();
+    let mut lines = vec![];
+    let has_headers = table_params.headers.iter().any(|header| !header.is_empty());
+    if has_headers || format == ExportFormat::Markdown {
+        let headers = match format {
+            ExportFormat::Markdown => table_params
+                .headers
+                .iter()
+                .map(|header| escape_markdown(header))
+                .collect(),
+            ExportFormat::Text => table_params.headers.clone(),
+        };
+        lines.push(row_line(headers));
+    }
+    if format == ExportFormat::Markdown {
+        lines.push(row_line(vec!["---".to_string(); column_count]));
+    }
+    for row in &table_params.rows {
+        let cells = (0..column_count)
+            .map(|column| row.cells.get(column).map(cell_text).unwrap_or_default())
+            .collect();
+        lines.push(row_line(cells));
+    }
+    lines
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '*' | '_' | '[' | ']' | '`' | '|') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
@@ -0,0 +1,62 @@
+use image::{ImageFormat, ImageReader};
+use log::debug;
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg::{Options, Tree};
+use std::io::Cursor;
+
+const SVG_SNIFF_LENGTH: usize = 512;
+const MAX_SVG_SIZE: f32 = 2048.0;
+
+// Textures are only loaded reliably from png, so everything else is converted first.
+// Animated images keep their first frame.
+pub fn normalize_to_png(bytes: Vec<u8>) -> Result<Vec<u8>, String> {
+    if is_svg(&bytes) {
+        debug!("[normalize_to_png] rasterizing svg");
+        return rasterize_svg(&bytes);
+    }
+    let reader = ImageReader::new(Cursor::new(&bytes))
+        .with_guessed_format()
+        .map_err(|e| format!("Couldn't read image: {e}"))?;
+    match reader.format() {
+        Some(ImageFormat::Png) => Ok(bytes),
+        Some(format) => {
+            debug!("[normalize_to_png] transcoding {format:?}");
+            let image = reader
+                .decode()
+                .map_err(|e| format!("Couldn't decode {format:?} image: {e}"))?;
+            let mut result = Cursor::new(vec![]);
+            image
+                .write_to(&mut result, ImageFormat::Png)
+                .map_err(|e| format!("Couldn't encode png: {e}"))?;
+            Ok(result.into_inner())
+        }
+        None => Err("Unknown image format".to_string()),
+    }
+}
+
+fn is_svg(bytes: &[u8]) -> bool {
+    let start = String::from_utf8_lossy(&bytes[..bytes.len().min(SVG_SNIFF_LENGTH)]);
+    let start = start.trim_start();
+    (start.starts_with("<?xml") || start.starts_with("<svg") || start.starts_with("<!--"))
+        && start.contains("<svg")
+}
+
+fn rasterize_svg(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let tree = Tree::from_data(bytes, &Options::default())
+        .map_err(|e| format!("Couldn't parse svg: {e}"))?;
+    let size = tree.size();
+    // the declared size can be arbitrary, so big drawings are scaled down
+    let scale = (MAX_SVG_SIZE / size.width().max(size.height())).min(1.0);
+    let width = (size.width() * scale).ceil() as u32;
+    let height = (size.height() * scale).ceil() as u32;
+    let mut pixmap =
+        Pixmap::new(width, height).ok_or_else(|| format!("Invalid svg size {width}x{height}"))?;
+    resvg::render(
+        &tree,
+        Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+    pixmap
+        .encode_png()
+        .map_err(|e| format!("Couldn't encode png: {e}"))
+}
@@ -0,0 +1,53 @@
+use crate::configuration::layouts::PinnedPopupLayout;
+use crate::state::cache::StoreInCache;
+use crate::state::context::Context;
+use crate::state::popup::popup_data::PopupData;
+use crate::state::popup::popup_state::PopupState;
+use crate::state::popup::Popup;
+use crate::state::threads::link::navigate_popup_thread;
+use log::debug;
+
+pub fn capture_layout(pinned_popups: &[Popup]) -> Vec<PinnedPopupLayout> {
+    pinned_popups
+        .iter()
+        .map(PinnedPopupLayout::from_popup)
+        .collect()
+}
+
+// Replaces the pinned popups, content missing from the popup data cache is refetched.
+pub fn restore_layout(context: &mut Context, layout: &[PinnedPopupLayout]) {
+    debug!("[restore_layout] Restoring {} pinned popups", layout.len());
+    let mut pinned_popups = vec![];
+    for popup_layout in layout {
+        let cached_data = context.cache.popup_data_map.retrieve(&popup_layout.href);
+        let is_cached = cached_data.is_some();
+        let mut data = cached_data.unwrap_or_else(|| PopupData {
+            title: popup_layout.title.clone(),
+            href: popup_layout.href.clone(),
+            ..PopupData::default()
+        });
+        if let Some(item_names) = context.cache.item_names.retrieve(()) {
+            data.item_ids = item_names.get(&popup_layout.title).cloned();
+        }
+        let state = PopupState {
+            opened: true,
+            pinned: true,
+            pos: popup_layout.pos,
+            width: popup_layout.width,
+            collapsed: popup_layout.collapsed,
+            selected_tab: popup_layout.selected_tab.clone(),
+            restore_selected_tab: popup_layout.selected_tab.is_some(),
+            ..PopupState::new_with_quantity(popup_layout.item_quantity)
+        };
+        if !is_cached {
+            navigate_popup_thread(
+                state.id,
+                popup_layout.href.clone(),
+                popup_layout.title.clone(),
+                None,
+            );
+        }
+        pinned_popups.push(Popup { data, state });
+    }
+    context.ui.pinned_popups = pinned_popups;
+}
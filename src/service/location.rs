@@ -0,0 +1,117 @@
+use crate::service::popup::process_text;
+use crate::state::popup::location_details::LocationDetails;
+use crate::state::popup::tag_params::TagParams;
+use crate::state::popup::Popup;
+use log::debug;
+use scraper::{ElementRef, Html, Selector};
+
+const ZONE_INFOBOX_KEY: &str = "Zone";
+const REGION_INFOBOX_KEY: &str = "Region";
+const AREA_INFOBOX_KEY: &str = "Area";
+const CHAT_LINK_INFOBOX_KEY: &str = "Chat link";
+const VENDORS_CATEGORY: &str = "Vendors";
+const HEARTS_CATEGORY: &str = "Hearts";
+const EVENTS_CATEGORY: &str = "Events";
+
+pub fn fill_location_details(document: &Html, popup: &mut Popup) {
+    debug!("[fill_location_details]");
+    let infobox = &popup.data.infobox;
+    if popup.data.item_ids.is_some()
+        || !(infobox.contains_key(ZONE_INFOBOX_KEY) || infobox.contains_key(REGION_INFOBOX_KEY))
+    {
+        return;
+    }
+    let mut location_details = LocationDetails {
+        chat_code: infobox
+            .get(CHAT_LINK_INFOBOX_KEY)
+            .filter(|chat_link| chat_link.starts_with("[&"))
+            .cloned(),
+        map_name: infobox.get(ZONE_INFOBOX_KEY).cloned(),
+        region: infobox.get(REGION_INFOBOX_KEY).cloned(),
+        area: infobox.get(AREA_INFOBOX_KEY).cloned(),
+        ..LocationDetails::default()
+    };
+    fill_nearby(document, &mut location_details);
+    popup.data.location = Some(location_details);
+}
+
+pub fn attach_chat_code(popup: &mut Popup, chat_code: &str) {
+    popup
+        .data
+        .location
+        .get_or_insert_with(LocationDetails::default)
+        .chat_code
+        .get_or_insert_with(|| chat_code.to_string());
+}
+
+fn fill_nearby(document: &Html, location_details: &mut LocationDetails) {
+    let heading_selector =
+        Selector::parse("div.mw-parser-output > h2, div.mw-parser-output > h3").unwrap();
+    let headline_selector = Selector::parse(".mw-headline").unwrap();
+    let link_selector = Selector::parse("a[title]:not(.external, .extiw, .image)").unwrap();
+    for heading in document.select(&heading_selector) {
+        let Some(category) = heading
+            .select(&headline_selector)
+            .next()
+            .map(|headline| headline.text().collect::<String>())
+            .and_then(|headline| nearby_category(&headline))
+        else {
+            continue;
+        };
+        let heading_name = heading.value().name();
+        let mut next = heading.next_sibling();
+        while let Some(node) = next {
+            next = node.next_sibling();
+            let Some(element) = ElementRef::wrap(node) else {
+                continue;
+            };
+            let tag_name = element.value().name();
+            if tag_name == "h2" || tag_name == heading_name {
+                break;
+            }
+            for link in element.select(&link_selector) {
+                push_nearby_link(location_details, category, link);
+            }
+        }
+    }
+}
+
+fn push_nearby_link(location_details: &mut LocationDetails, category: &str, link: ElementRef) {
+    let (Some(href), Some(title)) = (link.value().attr("href"), link.value().attr("title")) else {
+        return;
+    };
+    if !href.starts_with("/wiki/") || href.contains(':') {
+        return;
+    }
+    let href = href.split('#').next().unwrap_or(href).to_string();
+    let links = location_details
+        .nearby
+        .entry(category.to_string())
+        .or_default();
+    if links.iter().any(|tag| tag.href == href) {
+        return;
+    }
+    let text = process_text(&link.text().collect::<String>());
+    links.push(TagParams {
+        href,
+        text: if text.is_empty() {
+            title.to_string()
+        } else {
+            text
+        },
+        title: process_text(title),
+    });
+}
+
+fn nearby_category(headline: &str) -> Option<&'static str> {
+    let headline = headline.to_lowercase();
+    if headline.contains("vendor") || headline.contains("merchant") {
+        Some(VENDORS_CATEGORY)
+    } else if headline.contains("heart") {
+        Some(HEARTS_CATEGORY)
+    } else if headline.contains("event") {
+        Some(EVENTS_CATEGORY)
+    } else {
+        None
+    }
+}
@@ -1,6 +1,15 @@
+pub mod build_template;
+pub mod chat_code;
+pub mod container_contents;
 pub mod credential_manager;
+pub mod export;
 pub mod http_client;
+pub mod image_conversion;
 pub mod item_tag_parser;
 pub mod keyboard;
+pub mod layout;
+pub mod location;
 pub mod popup;
+pub mod price_history;
 pub mod search;
+pub mod trading_post;
@@ -1,10 +1,12 @@
 use crate::configuration::{read_config, write_config};
+use crate::service::container_contents::fill_container_contents;
+use crate::service::location::fill_location_details;
 use crate::state::context::write_context;
 use crate::state::popup::dimensions::Dimensions;
 use crate::state::popup::popup_data::SectionName;
 use crate::state::popup::style::Style;
 use crate::state::popup::style::Style::{Bold, Normal};
-use crate::state::popup::table_params::{TableCell, TableParams, TableRow};
+use crate::state::popup::table_params::{CellSpan, TableCell, TableParams, TableRow};
 use crate::state::popup::tag_params::TagParams;
 use crate::state::popup::token::Token;
 use crate::state::popup::Popup;
@@ -18,6 +20,8 @@ use scraper::{CaseSensitivity, ElementRef, Html, Node, Selector};
 use std::ops::Deref;
 use std::thread;
 
+const MAX_TABLE_SPAN: usize = 100;
+
 pub fn copy_popup_title(popup: &Popup) {
     let name = popup.data.title.clone();
     lock_threads().push(thread::spawn(move || {
@@ -31,6 +35,14 @@ pub fn copy_popup_title(popup: &Popup) {
     }));
 }
 
+pub fn copy_chat_code(chat_code: &str) {
+    let chat_code = chat_code.to_string();
+    lock_threads().push(thread::spawn(move || {
+        let _ = write_context().clipboard.set_text(chat_code.as_str());
+        send_alert("Chat code copied to clipboard.");
+    }));
+}
+
 pub fn close_all_popups() {
     lock_threads().push(thread::spawn(move || {
         write_context().ui.close_all_popups();
@@ -43,6 +55,7 @@ pub fn close_all_popups() {
 
 pub fn fill_popup_with_wiki_details(popup: &mut Popup, document: &Html) {
     fill_item_icon(document, popup);
+    fill_infobox(document, popup);
     fill_tags(document, popup);
     fill_description(document, popup);
     let section_selector = Selector::parse("h2").unwrap();
@@ -52,6 +65,33 @@ pub fn fill_popup_with_wiki_details(popup: &mut Popup, document: &Html) {
     }
     fill_notes(document, popup);
     fill_images(document, popup);
+    fill_location_details(document, popup);
+    fill_container_contents(document, popup);
+}
+
+fn fill_infobox(document: &Html, popup: &mut Popup) {
+    debug!("[fill_infobox]");
+    let term_selector = Selector::parse(".infobox dt").unwrap();
+    for term in document.select(&term_selector) {
+        let key = process_text(&term.text().collect::<Vec<_>>().join(" "));
+        let Some(definition) = term
+            .next_siblings()
+            .filter_map(ElementRef::wrap)
+            .next()
+            .filter(|e| e.value().name() == "dd")
+        else {
+            continue;
+        };
+        let value = definition
+            .text()
+            .map(process_text)
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !key.is_empty() && !value.is_empty() {
+            popup.data.infobox.entry(key).or_insert(value);
+        }
+    }
 }
 
 fn fill_item_icon(document: &Html, popup: &mut Popup) {
@@ -294,59 +334,174 @@ fn parse_element_node(
 
 fn parse_table(element: &ElementRef, result: &mut Vec<Token>) {
     let mut table_params = TableParams::new();
-    table_params.headers = parse_table_headers(element);
-    table_params.rows = parse_table_rows(element);
-    if table_params.headers.is_empty() {
-        let max_cells = table_params
-            .rows
+    let rows = table_row_elements(element);
+    let grid = parse_table_grid(&rows);
+    // leading rows made only of th cells form the (possibly multi-row) header
+    let header_row_count = rows
+        .iter()
+        .take_while(|row| {
+            let mut cells = table_cell_elements(row).peekable();
+            cells.peek().is_some() && cells.all(|cell| cell.value().name() == "th")
+        })
+        .count();
+    let column_count = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+    if header_row_count > 0 && header_row_count < grid.len() {
+        table_params.headers = (0..column_count)
+            .map(|column| table_header_text(&grid[..header_row_count], column))
+            .collect();
+        table_params.rows = grid[header_row_count..]
             .iter()
-            .map(|row| row.cells.len())
-            .max()
-            .unwrap_or(0);
-        for _ in 0..max_cells {
-            table_params.headers.push(String::new());
-        }
+            .cloned()
+            .map(|cells| TableRow { cells })
+            .collect();
+    } else {
+        table_params.headers = vec![String::new(); column_count];
+        table_params.rows = grid.into_iter().map(|cells| TableRow { cells }).collect();
     }
+    table_params.rows.retain(|row| !row.cells.is_empty());
     result.push(Token::Spacing);
     result.push(Token::Table(table_params));
     result.push(Token::Spacing);
 }
 
-fn parse_table_rows(element: &ElementRef) -> Vec<TableRow> {
-    let mut table_rows = vec![];
-    let row_selector = Selector::parse("tbody > tr").unwrap();
-    let rows = element.select(&row_selector);
-    for row in rows {
-        table_rows.push(parse_table_row(&row));
+// Rows of this table only, not of the tables nested in its cells.
+fn table_row_elements<'a>(table: &ElementRef<'a>) -> Vec<ElementRef<'a>> {
+    let mut rows = vec![];
+    for child in table.children().filter_map(ElementRef::wrap) {
+        match child.value().name() {
+            "tr" => rows.push(child),
+            "thead" | "tbody" | "tfoot" => rows.extend(
+                child
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .filter(|row| row.value().name() == "tr"),
+            ),
+            _ => {}
+        }
     }
-    table_rows
+    rows
+}
+
+fn table_cell_elements<'a>(row: &ElementRef<'a>) -> impl Iterator<Item = ElementRef<'a>> {
+    row.children()
+        .filter_map(ElementRef::wrap)
+        .filter(|cell| matches!(cell.value().name(), "td" | "th"))
 }
 
-fn parse_table_row(row: &ElementRef) -> TableRow {
-    let mut table_row = TableRow::new();
-    let cell_selector = Selector::parse("tr > td").unwrap();
-    let cells = row.select(&cell_selector);
-    for cell in cells {
-        table_row.cells.push(parse_table_cell(&cell))
+// Expands rowspan and colspan so that every row has one cell per column it covers.
+fn parse_table_grid(rows: &[ElementRef]) -> Vec<Vec<TableCell>> {
+    let mut grid: Vec<Vec<Option<TableCell>>> = vec![vec![]; rows.len()];
+    for (row_index, row) in rows.iter().enumerate() {
+        let mut column = 0;
+        for cell in table_cell_elements(row) {
+            while grid[row_index].get(column).is_some_and(Option::is_some) {
+                column += 1;
+            }
+            let row_span = table_span(&cell, "rowspan").min(rows.len() - row_index);
+            let column_span = table_span(&cell, "colspan");
+            let table_cell = parse_table_cell(&cell);
+            for (spanned_row, slots) in grid[row_index..row_index + row_span].iter_mut().enumerate()
+            {
+                if slots.len() < column + column_span {
+                    slots.resize(column + column_span, None);
+                }
+                for spanned_column in 0..column_span {
+                    let span = match (spanned_row, spanned_column) {
+                        (0, 0) => CellSpan::Origin,
+                        (_, 0) => CellSpan::Row,
+                        _ => CellSpan::Column,
+                    };
+                    let slot = &mut slots[column + spanned_column];
+                    if slot.is_none() {
+                        *slot = Some(TableCell {
+                            span,
+                            ..table_cell.clone()
+                        });
+                    }
+                }
+            }
+            column += column_span;
+        }
     }
-    table_row
+    grid.into_iter()
+        .map(|row| row.into_iter().map(Option::unwrap_or_default).collect())
+        .collect()
+}
+
+fn table_span(cell: &ElementRef, attribute: &str) -> usize {
+    cell.value()
+        .attr(attribute)
+        .and_then(|span| span.trim().parse::<usize>().ok())
+        .unwrap_or(1)
+        .clamp(1, MAX_TABLE_SPAN)
+}
+
+fn table_header_text(header_rows: &[Vec<TableCell>], column: usize) -> String {
+    let mut parts: Vec<String> = vec![];
+    for row in header_rows {
+        let Some(cell) = row.get(column) else {
+            continue;
+        };
+        let text = cell
+            .tokens
+            .iter()
+            .filter_map(|token| match token {
+                Token::Text(text, _) => Some(text.as_str()),
+                Token::Tag(tag_params) => Some(tag_params.text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<&str>>()
+            .join(" ");
+        if !text.is_empty() && !parts.contains(&text) {
+            parts.push(text);
+        }
+    }
+    parts.join(" / ")
 }
 
 fn parse_table_cell(cell: &ElementRef) -> TableCell {
     let mut table_cell = TableCell::new();
     parse_node(&mut table_cell.tokens, *cell.deref());
+    table_cell.tokens = flatten_nested_tables(table_cell.tokens);
+    // row header cells in the body keep standing out from the data cells
+    if cell.value().name() == "th" {
+        for token in &mut table_cell.tokens {
+            if let Token::Text(_, style @ Normal) = token {
+                *style = Bold;
+            }
+        }
+    }
     table_cell
 }
 
-fn parse_table_headers(element: &ElementRef) -> Vec<String> {
-    let mut table_headers = vec![];
-
-    let header_selector = Selector::parse("tbody > tr:first-child > th").unwrap();
-    let headers = element.select(&header_selector);
-    for header in headers {
-        table_headers.push(header.text().collect::<Vec<_>>().join(" "));
+// Tables can't be rendered inside table cells, so nested ones become a line per row.
+fn flatten_nested_tables(tokens: Vec<Token>) -> Vec<Token> {
+    let mut result = vec![];
+    for token in tokens {
+        let Token::Table(table_params) = token else {
+            result.push(token);
+            continue;
+        };
+        let headers = table_params
+            .headers
+            .iter()
+            .filter(|header| !header.is_empty())
+            .cloned()
+            .collect::<Vec<String>>();
+        if !headers.is_empty() {
+            result.push(Token::Spacing);
+            result.push(Token::Text(headers.join(" "), Bold));
+        }
+        for row in table_params.rows {
+            result.push(Token::Spacing);
+            for cell in row.cells {
+                if matches!(cell.span, CellSpan::Origin) {
+                    result.extend(cell.tokens);
+                }
+            }
+        }
     }
-    table_headers
+    result
 }
 
 pub fn process_text(text: &str) -> String {
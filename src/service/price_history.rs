@@ -0,0 +1,69 @@
+use crate::api::api_error::ApiError;
+use crate::api::datawars2::get_price_history;
+use crate::configuration::price_history_source::PriceHistorySource;
+use crate::configuration::read_config;
+use crate::state::cache::price_history::{covers_period, merge_price_history, PricePoint};
+use crate::state::context::{read_context, write_context};
+use crate::threads::lock_threads;
+use chrono::{Local, TimeDelta};
+use log::debug;
+use std::thread;
+
+pub trait PriceHistoryProvider: Send {
+    fn fetch_price_history(&self, item_id: u32) -> Result<Vec<PricePoint>, ApiError>;
+}
+
+pub struct DataWars2PriceHistoryProvider;
+
+impl PriceHistoryProvider for DataWars2PriceHistoryProvider {
+    fn fetch_price_history(&self, item_id: u32) -> Result<Vec<PricePoint>, ApiError> {
+        Ok(get_price_history(item_id)?
+            .into_iter()
+            .filter_map(|entry| {
+                Some(PricePoint {
+                    date: entry.date.with_timezone(&Local),
+                    highest_buy: entry.buy_price_max?,
+                    lowest_sell: entry.sell_price_min?,
+                })
+            })
+            .collect())
+    }
+}
+
+fn price_history_provider(source: &PriceHistorySource) -> Option<Box<dyn PriceHistoryProvider>> {
+    match source {
+        PriceHistorySource::LocalOnly => None,
+        PriceHistorySource::DataWars2 => Some(Box::new(DataWars2PriceHistoryProvider)),
+    }
+}
+
+pub fn seed_price_history_thread(item_ids: Vec<u32>) {
+    let Some(provider) = price_history_provider(&read_config().price_history_source) else {
+        return;
+    };
+    lock_threads().push(thread::spawn(move || {
+        let item_ids: Vec<u32> = {
+            let price_history = &read_context().cache.price_history;
+            item_ids
+                .into_iter()
+                .filter(|item_id| {
+                    !price_history
+                        .get(item_id)
+                        .is_some_and(|points| covers_period(points, TimeDelta::days(1)))
+                })
+                .collect()
+        };
+        debug!(
+            "[seed_price_history_thread] started for {} items",
+            item_ids.len()
+        );
+        for item_id in item_ids {
+            match provider.fetch_price_history(item_id) {
+                Ok(seed) => {
+                    merge_price_history(&mut write_context().cache.price_history, item_id, seed)
+                }
+                Err(api_error) => api_error.log(),
+            }
+        }
+    }));
+}
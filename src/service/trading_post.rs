@@ -0,0 +1,38 @@
+const LISTING_FEE_PERCENT: u32 = 5;
+const EXCHANGE_FEE_PERCENT: u32 = 10;
+const MIN_FEE: u32 = 1;
+const MAX_BREAK_EVEN_STEPS: u32 = 10;
+
+pub fn listing_fee(price: u32) -> u32 {
+    fee(price, LISTING_FEE_PERCENT)
+}
+
+pub fn exchange_fee(price: u32) -> u32 {
+    fee(price, EXCHANGE_FEE_PERCENT)
+}
+
+pub fn net_proceeds(price: u32) -> u32 {
+    price.saturating_sub(listing_fee(price) + exchange_fee(price))
+}
+
+// Lowest sell price whose proceeds after fees cover the given cost.
+pub fn break_even_price(cost: u32) -> u32 {
+    let fee_percent = (LISTING_FEE_PERCENT + EXCHANGE_FEE_PERCENT) as u64;
+    let estimate = (cost as u64 * 100) / (100 - fee_percent);
+    let mut price = estimate.saturating_sub(2).min(u32::MAX as u64) as u32;
+    // rounding of the fees leaves the estimate only a few coppers off
+    for _ in 0..MAX_BREAK_EVEN_STEPS {
+        if net_proceeds(price) >= cost {
+            return price;
+        }
+        match price.checked_add(1) {
+            Some(next) => price = next,
+            None => break,
+        }
+    }
+    price
+}
+
+fn fee(price: u32, percent: u32) -> u32 {
+    (((price as u64 * percent as u64 + 50) / 100) as u32).max(MIN_FEE)
+}
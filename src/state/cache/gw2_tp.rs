@@ -1,4 +1,4 @@
-use crate::api::gw2_tp::get_item_names;
+use crate::api::provider::fetch_item_names;
 use crate::state::cache::cached_data::CachedData;
 use crate::state::cache::caching_status::CachingStatus::Cached;
 use crate::state::cache::is_cache_expired;
@@ -21,7 +21,7 @@ pub fn cache_item_names_thread() {
             return;
         }
 
-        match get_item_names() {
+        match fetch_item_names() {
             Ok(items_response) => {
                 let ids_grouped_by_item_name: HashMap<String, Vec<u32>> = items_response
                     .items
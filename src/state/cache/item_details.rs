@@ -0,0 +1,108 @@
+use crate::api::gw2::item::get_items;
+use crate::api::gw2::price::MAX_IDS_PER_REQUEST;
+use crate::state::cache::cached_data::CachedData;
+use crate::state::cache::caching_status::CachingStatus::{Cached, Caching, Failed, Refreshing};
+use crate::state::cache::{is_cache_expired, StoreInCache};
+use crate::state::context::write_context;
+use crate::threads::lock_threads;
+use chrono::Local;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+
+const ITEM_DETAILS_CACHE_EXPIRATION: Duration = Duration::from_secs(60 * 60 * 24);
+const FAILED_ITEM_DETAILS_RETRY_COOLDOWN: Duration = Duration::from_secs(60);
+const NOT_TRADABLE_FLAGS: [&str; 2] = ["AccountBound", "SoulbindOnAcquire"];
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ItemDetails {
+    pub rarity: String,
+    pub tradable: bool,
+}
+
+pub type ItemDetailsCache = HashMap<u32, CachedData<ItemDetails>>;
+
+impl<'a> StoreInCache<'a, ItemDetailsCache, ItemDetailsCache, Vec<u32>> for ItemDetailsCache {
+    fn retrieve(&'a mut self, key: Vec<u32>) -> Option<ItemDetailsCache> {
+        let mut ids_to_cache = vec![];
+        let mut result = HashMap::new();
+        for item_id in key {
+            match self.get_mut(&item_id) {
+                Some(item_details) => {
+                    let expiration = match item_details.caching_status {
+                        Failed => FAILED_ITEM_DETAILS_RETRY_COOLDOWN,
+                        _ => ITEM_DETAILS_CACHE_EXPIRATION,
+                    };
+                    if is_cache_expired(expiration, item_details.date)
+                        && !matches!(item_details.caching_status, Caching | Refreshing)
+                    {
+                        item_details.caching_status = match item_details.caching_status {
+                            Failed => Caching,
+                            _ => Refreshing,
+                        };
+                        ids_to_cache.push(item_id);
+                    }
+                    result.insert(item_id, item_details.clone());
+                }
+                None => {
+                    let item_details = CachedData::new(Local::now());
+                    self.insert(item_id, item_details.clone());
+                    result.insert(item_id, item_details);
+                    ids_to_cache.push(item_id);
+                }
+            }
+        }
+        if !ids_to_cache.is_empty() {
+            cache_item_details_thread(ids_to_cache);
+        }
+        Some(result)
+    }
+}
+
+pub fn cache_item_details_thread(item_ids: Vec<u32>) {
+    lock_threads().push(thread::spawn(move || {
+        debug!(
+            "[cache_item_details_thread] started for {} items",
+            item_ids.len()
+        );
+        for chunk in item_ids.chunks(MAX_IDS_PER_REQUEST) {
+            match get_items(chunk) {
+                Ok(items) => {
+                    let mut context = write_context();
+                    for item_id in chunk {
+                        // ids missing from the response are not valid items
+                        let item_details = match items.iter().find(|item| item.id == *item_id) {
+                            Some(item) => CachedData::new_with_value(
+                                Local::now(),
+                                ItemDetails {
+                                    rarity: item.rarity.clone(),
+                                    tradable: !item
+                                        .flags
+                                        .iter()
+                                        .any(|flag| NOT_TRADABLE_FLAGS.contains(&flag.as_str())),
+                                },
+                            ),
+                            None => CachedData::new(Local::now()),
+                        };
+                        context
+                            .cache
+                            .item_details
+                            .insert(*item_id, item_details.with_caching_status(Cached));
+                    }
+                }
+                Err(api_error) => {
+                    api_error.log();
+                    let mut context = write_context();
+                    for item_id in chunk {
+                        context.cache.item_details.insert(
+                            *item_id,
+                            CachedData::new(Local::now()).with_caching_status(Failed),
+                        );
+                    }
+                }
+            }
+        }
+    }));
+}
@@ -12,6 +12,13 @@ use crate::state::cache::StoreInCache;
 
 pub type ItemNamesCache = HashMap<String, Vec<u32>>;
 
+pub fn find_item_name(item_names: &ItemNamesCache, item_id: u32) -> Option<String> {
+    item_names
+        .iter()
+        .find(|(_, item_ids)| item_ids.contains(&item_id))
+        .map(|(item_name, _)| item_name.clone())
+}
+
 impl Persist for CachedData<ItemNamesCache> {
     fn load(&mut self) {
         let path = CachedData::<ItemNamesCache>::file_path();
@@ -0,0 +1,126 @@
+use crate::api::gw2::listings::get_listings;
+use crate::api::gw2::listings::listings_api_response::ListingApiResponse;
+use crate::configuration::read_config;
+use crate::state::cache::cached_data::CachedData;
+use crate::state::cache::caching_status::CachingStatus;
+use crate::state::cache::caching_status::CachingStatus::{Cached, Failed};
+use crate::state::cache::{is_cache_expired, StoreInCache};
+use crate::state::context::write_context;
+use crate::threads::lock_threads;
+use chrono::Local;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::thread;
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ListingLevel {
+    pub unit_price: u32,
+    pub quantity: u32,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct Listings {
+    // highest price first
+    pub buys: Vec<ListingLevel>,
+    // lowest price first
+    pub sells: Vec<ListingLevel>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BookWalk {
+    pub quantity: u32,
+    pub total: u32,
+}
+
+impl BookWalk {
+    pub fn average_unit_price(&self) -> u32 {
+        if self.quantity == 0 {
+            return 0;
+        }
+        self.total / self.quantity
+    }
+}
+
+// Fills as much of the requested quantity as the given side of the book allows.
+pub fn walk_book(levels: &[ListingLevel], quantity: u32) -> BookWalk {
+    let mut result = BookWalk {
+        quantity: 0,
+        total: 0,
+    };
+    for level in levels {
+        let remaining = quantity - result.quantity;
+        if remaining == 0 {
+            break;
+        }
+        let taken = remaining.min(level.quantity);
+        result.quantity += taken;
+        result.total = result
+            .total
+            .saturating_add(taken.saturating_mul(level.unit_price));
+    }
+    result
+}
+
+pub type ListingsCache = HashMap<u32, CachedData<Listings>>;
+
+impl<'a> StoreInCache<'a, ListingsCache, CachedData<Listings>, u32> for ListingsCache {
+    fn retrieve(&'a mut self, item_id: u32) -> Option<CachedData<Listings>> {
+        match self.get_mut(&item_id) {
+            Some(listings) => {
+                if is_cache_expired(read_config().max_price_expiration_duration, listings.date)
+                    && !matches!(
+                        &listings.caching_status,
+                        CachingStatus::Refreshing | CachingStatus::Caching
+                    )
+                {
+                    listings.caching_status = CachingStatus::Refreshing;
+                    cache_listings_thread(item_id);
+                }
+                Some(listings.clone())
+            }
+            None => {
+                let listings = CachedData::new(Local::now());
+                self.insert(item_id, listings.clone());
+                cache_listings_thread(item_id);
+                Some(listings)
+            }
+        }
+    }
+}
+
+pub fn cache_listings_thread(item_id: u32) {
+    lock_threads().push(thread::spawn(move || {
+        debug!("[cache_listings_thread] started for {item_id}");
+
+        let cached_listings = match get_listings(item_id) {
+            Ok(listings) => {
+                let mut buys = to_levels(listings.buys);
+                buys.sort_by_key(|level| Reverse(level.unit_price));
+                let mut sells = to_levels(listings.sells);
+                sells.sort_by_key(|level| level.unit_price);
+                CachedData::new_with_value(Local::now(), Listings { buys, sells })
+                    .with_caching_status(Cached)
+            }
+            Err(api_error) => {
+                api_error.log();
+                CachedData::new(Local::now()).with_caching_status(Failed)
+            }
+        };
+        write_context()
+            .cache
+            .listings
+            .insert(item_id, cached_listings);
+    }));
+}
+
+fn to_levels(listings: Vec<ListingApiResponse>) -> Vec<ListingLevel> {
+    listings
+        .into_iter()
+        .map(|listing| ListingLevel {
+            unit_price: listing.unit_price,
+            quantity: listing.quantity,
+        })
+        .collect()
+}
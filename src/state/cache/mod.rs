@@ -1,8 +1,14 @@
 pub mod cached_data;
 pub mod caching_status;
 pub mod gw2_tp;
+pub mod item_details;
 pub mod item_name;
+pub mod listings;
+pub mod notes;
 pub mod price;
+pub mod price_history;
+pub mod profession;
+pub mod tag_preview;
 pub mod texture;
 
 use chrono::{DateTime, Local};
@@ -11,8 +17,14 @@ use std::path::PathBuf;
 use std::time::Duration;
 
 use crate::state::cache::cached_data::CachedData;
+use crate::state::cache::item_details::ItemDetailsCache;
 use crate::state::cache::item_name::ItemNamesCache;
+use crate::state::cache::listings::ListingsCache;
+use crate::state::cache::notes::Notes;
 use crate::state::cache::price::PriceCache;
+use crate::state::cache::price_history::PriceHistoryCache;
+use crate::state::cache::profession::ProfessionsCache;
+use crate::state::cache::tag_preview::TagPreviewCache;
 use crate::state::cache::texture::TextureCache;
 use crate::state::popup::popup_data::PopupDataCache;
 
@@ -20,16 +32,27 @@ use crate::state::popup::popup_data::PopupDataCache;
 pub struct Cache {
     pub popup_data_map: PopupDataCache,
     pub item_names: CachedData<ItemNamesCache>,
+    pub item_details: ItemDetailsCache,
     pub prices: PriceCache,
+    pub listings: ListingsCache,
+    pub price_history: PriceHistoryCache,
+    pub professions: CachedData<ProfessionsCache>,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub notes: Notes,
     #[serde(skip_serializing, skip_deserializing)]
     pub textures: TextureCache,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub tag_previews: TagPreviewCache,
 }
 
 impl Cache {
     pub fn evict(&mut self) {
         self.popup_data_map.clear();
         self.prices.clear();
+        self.item_details.clear();
+        self.listings.clear();
         self.textures.clear();
+        self.tag_previews.clear();
     }
 }
 
@@ -0,0 +1,114 @@
+use crate::configuration::config_dir;
+use crate::state::cache::Persist;
+use chrono::{DateTime, Local};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Note {
+    pub title: String,
+    pub text: String,
+    pub date: DateTime<Local>,
+}
+
+// Notes are written by the user, so unlike the rest of the cache they never expire.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Notes {
+    notes: HashMap<String, Note>,
+    #[serde(skip)]
+    changed_at: Option<Instant>,
+}
+
+impl Notes {
+    pub fn get(&self, href: &str) -> Option<&Note> {
+        self.notes.get(href)
+    }
+
+    pub fn set(&mut self, href: &str, title: &str, text: String) {
+        self.changed_at = Some(Instant::now());
+        if text.trim().is_empty() {
+            self.notes.remove(href);
+            return;
+        }
+        self.notes.insert(
+            href.to_string(),
+            Note {
+                title: title.to_string(),
+                text,
+                date: Local::now(),
+            },
+        );
+    }
+
+    // Whether there are edits older than the delay that were not written to disk yet.
+    pub fn has_unsaved_changes(&self, delay: Duration) -> bool {
+        self.changed_at
+            .is_some_and(|changed_at| changed_at.elapsed() >= delay)
+    }
+
+    // Edits made after the saved copy was taken stay pending.
+    pub fn mark_saved(&mut self, saved: &Notes) {
+        if self.changed_at == saved.changed_at {
+            self.changed_at = None;
+        }
+    }
+
+    // (href, note) of the notes whose title or text contain the needle, most recent first.
+    pub fn find_containing(&self, needle: &str, max_results: usize) -> Vec<(&String, &Note)> {
+        let needle = needle.to_lowercase();
+        let mut result: Vec<(&String, &Note)> = self
+            .notes
+            .iter()
+            .filter(|(_, note)| {
+                note.title.to_lowercase().contains(&needle)
+                    || note.text.to_lowercase().contains(&needle)
+            })
+            .collect();
+        result.sort_by_key(|(_, note)| Reverse(note.date));
+        result.truncate(max_results);
+        result
+    }
+}
+
+impl Persist for Notes {
+    fn load(&mut self) {
+        let path = Notes::file_path();
+        if !path.exists() {
+            info!("[load] No notes saved yet");
+            return;
+        }
+        let Ok(file) =
+            File::open(&path).inspect_err(|err| warn!("[load] Failed to read notes: {err}"))
+        else {
+            return;
+        };
+        let reader = BufReader::new(file);
+        if let Ok(notes) = serde_json::from_reader(reader)
+            .inspect_err(|err| warn!("[load] Failed to parse notes: {err}"))
+        {
+            *self = notes;
+            info!("[load] Loaded notes from \"{}\"", path.display());
+        }
+    }
+
+    fn save(&self) {
+        let path = Notes::file_path();
+        match File::create(&path) {
+            Ok(file) => {
+                let writer = BufWriter::new(file);
+                serde_json::to_writer_pretty(writer, self).expect("failed to serialize notes");
+            }
+            Err(err) => log::error!("Failed to save notes: {err}"),
+        }
+    }
+
+    fn file_path() -> PathBuf {
+        config_dir().join("notes.json")
+    }
+}
@@ -1,17 +1,26 @@
-use crate::api::gw2::price::get_prices;
+use crate::api::api_error::ApiError;
 use crate::api::gw2::price::price_api_response::PriceApiResponse;
+use crate::api::gw2::price::MAX_IDS_PER_REQUEST;
+use crate::api::provider::fetch_prices;
 use crate::configuration::read_config;
+use crate::service::price_history::seed_price_history_thread;
 use crate::state::cache::cached_data::CachedData;
 use crate::state::cache::caching_status::CachingStatus;
 use crate::state::cache::caching_status::CachingStatus::{Cached, Failed};
-use crate::state::cache::{is_cache_expired, StoreInCache};
-use crate::state::context::write_context;
+use crate::state::cache::price_history::record_price;
+use crate::state::cache::{is_cache_expired, Cache, StoreInCache};
+use crate::state::context::{read_context, write_context};
 use crate::threads::lock_threads;
 use chrono::Local;
 use log::debug;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::thread;
+use std::time::Duration;
+
+const FAILED_PRICE_RETRY_COOLDOWN: Duration = Duration::from_secs(30);
+const INITIAL_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_FETCH_ATTEMPTS: u32 = 3;
 
 #[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Price {
@@ -24,9 +33,18 @@ pub type PriceCache = HashMap<u32, CachedData<Price>>;
 impl<'a> StoreInCache<'a, PriceCache, PriceCache, Vec<u32>> for PriceCache {
     fn retrieve(&'a mut self, key: Vec<u32>) -> Option<PriceCache> {
         let mut ids_to_cache = vec![];
+        let mut ids_to_seed = vec![];
         let mut result = HashMap::new();
         for item_id in key {
             match self.get_mut(&item_id) {
+                Some(price) if matches!(&price.caching_status, Failed) => {
+                    if is_cache_expired(FAILED_PRICE_RETRY_COOLDOWN, price.date) {
+                        price.caching_status = CachingStatus::Caching;
+                        price.date = Local::now();
+                        ids_to_cache.push(item_id);
+                    }
+                    result.insert(item_id, price.clone());
+                }
                 Some(price) => {
                     result.insert(item_id, price.clone());
                     if is_cache_expired(read_config().max_price_expiration_duration, price.date)
@@ -39,6 +57,7 @@ impl<'a> StoreInCache<'a, PriceCache, PriceCache, Vec<u32>> for PriceCache {
                 None => {
                     let price_to_cache = CachedData::new_with_value(Local::now(), Price::default());
                     ids_to_cache.push(item_id);
+                    ids_to_seed.push(item_id);
                     self.insert(item_id, price_to_cache.clone());
                     result.insert(item_id, price_to_cache);
                 }
@@ -47,6 +66,9 @@ impl<'a> StoreInCache<'a, PriceCache, PriceCache, Vec<u32>> for PriceCache {
         if !ids_to_cache.is_empty() {
             cache_prices_thread(ids_to_cache);
         }
+        if !ids_to_seed.is_empty() {
+            seed_price_history_thread(ids_to_seed);
+        }
         Some(result)
     }
 }
@@ -55,44 +77,122 @@ pub fn cache_prices_thread(item_ids: Vec<u32>) {
     lock_threads().push(thread::spawn(move || {
         debug!("[cache_prices_thread] started for {} items", item_ids.len());
 
-        match get_prices(&item_ids) {
-            Ok(prices) => cache_prices(&item_ids, prices),
-            Err(api_error) => {
-                api_error.log();
-                mark_price_caching_as_failed(item_ids);
+        for chunk in item_ids.chunks(MAX_IDS_PER_REQUEST) {
+            match fetch_prices_with_retry(chunk) {
+                Ok(prices) => cache_prices(chunk, prices),
+                Err(api_error) => {
+                    api_error.log();
+                    mark_price_caching_as_failed(chunk);
+                }
             }
         }
     }));
 }
 
-fn mark_price_caching_as_failed(ids_to_cache: Vec<u32>) {
+fn fetch_prices_with_retry(item_ids: &[u32]) -> Result<Vec<PriceApiResponse>, ApiError> {
+    let mut backoff = INITIAL_RETRY_BACKOFF;
+    let mut attempt = 1;
+    loop {
+        match fetch_prices(item_ids) {
+            Err(api_error)
+                if api_error.is_transient()
+                    && attempt < MAX_FETCH_ATTEMPTS
+                    && read_context().run_background_thread =>
+            {
+                api_error.log();
+                debug!("[fetch_prices_with_retry] retrying in {backoff:?}");
+                thread::sleep(backoff);
+                backoff *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn mark_price_caching_as_failed(ids_to_cache: &[u32]) {
     for id in ids_to_cache {
         write_context().cache.prices.insert(
-            id,
+            *id,
             CachedData::new(Local::now()).with_caching_status(Failed),
         );
     }
 }
 
-fn cache_prices(ids_to_cache: &Vec<u32>, prices: Vec<PriceApiResponse>) {
+fn cache_prices(ids_to_cache: &[u32], prices: Vec<PriceApiResponse>) {
+    store_prices(&mut write_context().cache, ids_to_cache, &prices);
+}
+
+// Ids missing from a successful response are not tradable.
+fn store_prices(cache: &mut Cache, ids_to_cache: &[u32], prices: &[PriceApiResponse]) {
     for price_data in prices {
         if !ids_to_cache.contains(&price_data.id) {
             continue;
         }
-        cache_price(price_data);
+        store_price(cache, price_data);
+    }
+    for id in ids_to_cache {
+        if prices.iter().any(|price_data| price_data.id == *id) {
+            continue;
+        }
+        cache.prices.insert(
+            *id,
+            CachedData::new(Local::now()).with_caching_status(Cached),
+        );
     }
 }
 
-fn cache_price(price_data: PriceApiResponse) {
+pub(crate) fn cache_price(price_data: &PriceApiResponse) {
+    store_price(&mut write_context().cache, price_data);
+}
+
+fn store_price(cache: &mut Cache, price_data: &PriceApiResponse) {
     let new_price = Price {
         highest_buy: price_data.buys.unit_price,
         lowest_sell: price_data.sells.unit_price,
     };
+    record_price(&mut cache.price_history, price_data.id, &new_price);
     let new_cached_price =
         CachedData::new_with_value(Local::now(), new_price).with_caching_status(Cached);
 
-    write_context()
-        .cache
-        .prices
-        .insert(price_data.id, new_cached_price);
+    cache.prices.insert(price_data.id, new_cached_price);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::provider::tests::fixture_provider;
+    use crate::api::provider::PriceProvider;
+
+    #[test]
+    fn stores_and_retrieves_known_prices() {
+        let mut cache = Cache::default();
+        let item_ids = vec![19721, 24];
+        let prices = fixture_provider().get_prices(&item_ids).unwrap();
+
+        store_prices(&mut cache, &item_ids, &prices);
+        let result = cache.prices.retrieve(item_ids).unwrap();
+
+        let ectoplasm = result[&19721].value().unwrap();
+        assert_eq!(ectoplasm.highest_buy, 2150);
+        assert_eq!(ectoplasm.lowest_sell, 2304);
+        let snowballs = result[&24].value().unwrap();
+        assert_eq!(snowballs.highest_buy, 105);
+        assert_eq!(snowballs.lowest_sell, 161);
+        assert_eq!(cache.price_history[&19721].len(), 1);
+    }
+
+    #[test]
+    fn stores_ids_missing_from_snapshot_as_cached_without_value() {
+        let mut cache = Cache::default();
+        let item_ids = vec![19721, 1];
+        let prices = fixture_provider().get_prices(&item_ids).unwrap();
+
+        store_prices(&mut cache, &item_ids, &prices);
+
+        let untradable = &cache.prices[&1];
+        assert!(matches!(untradable.caching_status, Cached));
+        assert!(untradable.value().is_none());
+        assert!(!cache.price_history.contains_key(&1));
+    }
 }
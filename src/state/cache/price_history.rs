@@ -0,0 +1,129 @@
+use crate::configuration::config_dir;
+use crate::state::cache::price::Price;
+use crate::state::cache::Persist;
+use chrono::{DateTime, Local, TimeDelta};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
+const MIN_SAMPLE_INTERVAL_MINUTES: i64 = 10;
+const RETENTION_DAYS: i64 = 31;
+const MAX_TRACKED_ITEMS: usize = 1000;
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PricePoint {
+    pub date: DateTime<Local>,
+    pub highest_buy: u32,
+    pub lowest_sell: u32,
+}
+
+// item id, points sorted by date
+pub type PriceHistoryCache = HashMap<u32, Vec<PricePoint>>;
+
+pub fn record_price(price_history: &mut PriceHistoryCache, item_id: u32, price: &Price) {
+    let now = Local::now();
+    let points = price_history.entry(item_id).or_default();
+    if points
+        .last()
+        .is_some_and(|last| now - last.date < TimeDelta::minutes(MIN_SAMPLE_INTERVAL_MINUTES))
+    {
+        return;
+    }
+    points.push(PricePoint {
+        date: now,
+        highest_buy: price.highest_buy,
+        lowest_sell: price.lowest_sell,
+    });
+    prune_price_history(price_history);
+}
+
+// Seeded points only fill the period before the first local sample.
+pub fn merge_price_history(
+    price_history: &mut PriceHistoryCache,
+    item_id: u32,
+    seed: Vec<PricePoint>,
+) {
+    let points = price_history.entry(item_id).or_default();
+    let first_local_date = points.first().map(|point| point.date);
+    let mut merged: Vec<PricePoint> = seed
+        .into_iter()
+        .filter(|point| match first_local_date {
+            Some(date) => point.date < date,
+            None => true,
+        })
+        .collect();
+    merged.sort_by_key(|point| point.date);
+    merged.append(points);
+    *points = merged;
+    prune_price_history(price_history);
+}
+
+pub fn history_since(points: &[PricePoint], period: TimeDelta) -> &[PricePoint] {
+    let since = Local::now() - period;
+    let start = points.partition_point(|point| point.date < since);
+    &points[start..]
+}
+
+pub fn covers_period(points: &[PricePoint], period: TimeDelta) -> bool {
+    points
+        .first()
+        .is_some_and(|point| point.date <= Local::now() - period)
+}
+
+fn prune_price_history(price_history: &mut PriceHistoryCache) {
+    let retention_start = Local::now() - TimeDelta::days(RETENTION_DAYS);
+    for points in price_history.values_mut() {
+        let start = points.partition_point(|point| point.date < retention_start);
+        points.drain(..start);
+    }
+    price_history.retain(|_, points| !points.is_empty());
+    if price_history.len() <= MAX_TRACKED_ITEMS {
+        return;
+    }
+    let mut last_updates: Vec<(u32, DateTime<Local>)> = price_history
+        .iter()
+        .filter_map(|(item_id, points)| points.last().map(|point| (*item_id, point.date)))
+        .collect();
+    last_updates.sort_by_key(|(_, date)| *date);
+    let excess = price_history.len() - MAX_TRACKED_ITEMS;
+    for (item_id, _) in last_updates.into_iter().take(excess) {
+        price_history.remove(&item_id);
+    }
+}
+
+impl Persist for PriceHistoryCache {
+    fn load(&mut self) {
+        let path = PriceHistoryCache::file_path();
+        let Ok(file) = File::open(&path)
+            .inspect_err(|err| warn!("[load] Failed to read price_history: {err}"))
+        else {
+            return;
+        };
+        let reader = BufReader::new(file);
+        if let Ok(price_history) = serde_json::from_reader(reader)
+            .inspect_err(|err| warn!("[load] Failed to parse price_history: {err}"))
+        {
+            *self = price_history;
+            prune_price_history(self);
+            info!("[load] Loaded price_history from \"{}\"", path.display());
+        }
+    }
+
+    fn save(&self) {
+        let path = PriceHistoryCache::file_path();
+        match File::create(&path) {
+            Ok(file) => {
+                let writer = BufWriter::new(file);
+                serde_json::to_writer(writer, self).expect("failed to serialize price_history");
+            }
+            Err(err) => log::error!("Failed to save price_history: {err}"),
+        }
+    }
+
+    fn file_path() -> PathBuf {
+        config_dir().join("price_history.json")
+    }
+}
@@ -0,0 +1,99 @@
+use crate::api::gw2::build::get_professions;
+use crate::configuration::config_dir;
+use crate::state::cache::cached_data::CachedData;
+use crate::state::cache::caching_status::CachingStatus::Cached;
+use crate::state::cache::{is_cache_expired, Persist};
+use crate::state::context::{read_context, write_context};
+use chrono::Local;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const PROFESSIONS_CACHE_EXPIRATION: Duration = Duration::from_secs(60 * 60 * 24 * 7);
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Profession {
+    pub name: String,
+    pub icon: String,
+    pub skills_by_palette: HashMap<u16, u32>,
+}
+
+// profession code used by build template chat codes, profession
+pub type ProfessionsCache = HashMap<u8, Profession>;
+
+pub fn retrieve_professions() -> Option<ProfessionsCache> {
+    let cached_professions = read_context().cache.professions.clone();
+    if !is_cache_expired(PROFESSIONS_CACHE_EXPIRATION, cached_professions.date()) {
+        if let Some(professions) = cached_professions.value() {
+            debug!("[retrieve_professions] cache is up to date");
+            return Some(professions.clone());
+        }
+    }
+
+    match get_professions() {
+        Ok(professions_response) => {
+            let professions: ProfessionsCache = professions_response
+                .into_iter()
+                .map(|profession| {
+                    (
+                        profession.code,
+                        Profession {
+                            name: profession.name,
+                            icon: profession.icon_big,
+                            skills_by_palette: profession.skills_by_palette.into_iter().collect(),
+                        },
+                    )
+                })
+                .collect();
+            write_context().cache.professions =
+                CachedData::new_with_value(Local::now(), professions.clone())
+                    .with_caching_status(Cached);
+            Some(professions)
+        }
+        Err(api_error) => {
+            api_error.log();
+            cached_professions.value().cloned()
+        }
+    }
+}
+
+impl Persist for CachedData<ProfessionsCache> {
+    fn load(&mut self) {
+        let path = CachedData::<ProfessionsCache>::file_path();
+        let Ok(file) = File::open(&path)
+            .inspect_err(|err| warn!("[load] Failed to read professions_cache: {err}"))
+        else {
+            return;
+        };
+        let reader = BufReader::new(file);
+        if let Ok(cache) = serde_json::from_reader(reader)
+            .inspect_err(|err| warn!("[load] Failed to parse professions_cache: {err}"))
+        {
+            *self = cache;
+            info!(
+                "[load] Loaded professions_cache from \"{}\"",
+                path.display()
+            );
+        }
+    }
+
+    fn save(&self) {
+        let path = CachedData::<ProfessionsCache>::file_path();
+        match File::create(&path) {
+            Ok(file) => {
+                let writer = BufWriter::new(file);
+                serde_json::to_writer_pretty(writer, self)
+                    .expect("failed to serialize professions_cache");
+            }
+            Err(err) => log::error!("Failed to save professions_cache: {err}"),
+        }
+    }
+
+    fn file_path() -> PathBuf {
+        config_dir().join("professions_cache.json")
+    }
+}
@@ -0,0 +1,149 @@
+use crate::api::gw2_wiki::get_wiki_article;
+use crate::configuration::read_config;
+use crate::service::popup::fill_popup_with_wiki_details;
+use crate::state::cache::cached_data::CachedData;
+use crate::state::cache::caching_status::CachingStatus::{Cached, Failed};
+use crate::state::cache::is_cache_expired;
+use crate::state::cache::StoreInCache;
+use crate::state::context::write_context;
+use crate::state::popup::popup_data::PopupData;
+use crate::state::popup::token::Token;
+use crate::state::popup::Popup;
+use crate::threads::lock_threads;
+use chrono::Local;
+use log::debug;
+use std::collections::HashMap;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const MAX_DESCRIPTION_LENGTH: usize = 300;
+const FAILED_PREVIEW_RETRY_COOLDOWN: Duration = Duration::from_secs(30);
+// a tag not hovered for longer than this has to wait for the full delay again
+const HOVER_RESET_DURATION: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Debug)]
+pub struct TagPreview {
+    pub icon: Option<Token>,
+    pub description: String,
+    pub item_ids: Option<Vec<u32>>,
+}
+
+impl TagPreview {
+    pub fn from_popup_data(data: &PopupData) -> Self {
+        Self {
+            icon: data.item_icon.clone(),
+            description: first_paragraph(&data.description),
+            item_ids: data.item_ids.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HoveredTag {
+    href: String,
+    since: Instant,
+    last_seen: Instant,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TagPreviewCache {
+    previews: HashMap<String, CachedData<TagPreview>>,
+    hovered: Option<HoveredTag>,
+}
+
+impl TagPreviewCache {
+    // Returns whether the tag has been hovered long enough for its preview to show.
+    pub fn hover(&mut self, href: &str, delay: Duration) -> bool {
+        let now = Instant::now();
+        match &mut self.hovered {
+            Some(hovered)
+                if hovered.href == href && now - hovered.last_seen < HOVER_RESET_DURATION =>
+            {
+                hovered.last_seen = now;
+                now - hovered.since >= delay
+            }
+            _ => {
+                self.hovered = Some(HoveredTag {
+                    href: href.to_string(),
+                    since: now,
+                    last_seen: now,
+                });
+                delay.is_zero()
+            }
+        }
+    }
+
+    pub fn insert(&mut self, href: String, preview: CachedData<TagPreview>) {
+        self.previews.insert(href, preview);
+    }
+
+    pub fn clear(&mut self) {
+        self.previews.clear();
+    }
+}
+
+impl<'a> StoreInCache<'a, TagPreviewCache, CachedData<TagPreview>, (&'a String, &'a String)>
+    for TagPreviewCache
+{
+    fn retrieve(
+        &'a mut self,
+        (href, title): (&'a String, &'a String),
+    ) -> Option<CachedData<TagPreview>> {
+        let expiration = match self.previews.get(href) {
+            Some(preview) if matches!(preview.caching_status, Failed) => {
+                FAILED_PREVIEW_RETRY_COOLDOWN
+            }
+            _ => read_config().max_popup_data_expiration_duration,
+        };
+        match self.previews.get(href) {
+            Some(preview) if !is_cache_expired(expiration, preview.date()) => Some(preview.clone()),
+            _ => {
+                let preview = CachedData::new(Local::now());
+                self.previews.insert(href.clone(), preview.clone());
+                cache_tag_preview_thread(href.clone(), title.clone());
+                Some(preview)
+            }
+        }
+    }
+}
+
+// Fetches the article without touching the hovered popup, so browsing stays non-destructive.
+pub fn cache_tag_preview_thread(href: String, title: String) {
+    lock_threads().push(thread::spawn(move || {
+        debug!("[cache_tag_preview_thread] started for {href}");
+        let mut popup = Popup::new_with(&href, title, &1);
+        let Some(document) = get_wiki_article(&href) else {
+            write_context().cache.tag_previews.insert(
+                href,
+                CachedData::new(Local::now()).with_caching_status(Failed),
+            );
+            return;
+        };
+        fill_popup_with_wiki_details(&mut popup, &document);
+        let preview = TagPreview::from_popup_data(&popup.data);
+        let mut context = write_context();
+        context.cache.popup_data_map.store(&href, &mut popup.data);
+        context.cache.tag_previews.insert(
+            href,
+            CachedData::new_with_value(Local::now(), preview).with_caching_status(Cached),
+        );
+    }));
+}
+
+fn first_paragraph(description: &[Token]) -> String {
+    let mut words = vec![];
+    for token in description {
+        match token {
+            Token::Text(text, _) => words.push(text.as_str()),
+            Token::Tag(tag_params) => words.push(tag_params.text.as_str()),
+            Token::Image(..) => {}
+            _ => break,
+        }
+    }
+    let paragraph = words.join(" ");
+    if paragraph.chars().count() <= MAX_DESCRIPTION_LENGTH {
+        return paragraph;
+    }
+    let truncated: String = paragraph.chars().take(MAX_DESCRIPTION_LENGTH).collect();
+    format!("{}..", truncated.trim_end())
+}
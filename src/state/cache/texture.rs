@@ -1,4 +1,8 @@
-use crate::api::gw2_wiki::download_wiki_image;
+use crate::api::api_error::ApiError;
+use crate::api::gw2_wiki::{
+    download_wiki_image, download_wiki_image_as, full_resolution_href, sized_thumbnail_href,
+    thumbnail_width,
+};
 use crate::configuration::read_config;
 use crate::configuration::textures_dir;
 use crate::state::cache::cached_data::CachedData;
@@ -12,11 +16,19 @@ use log::{debug, error};
 use nexus::texture::{load_texture_from_file, RawTextureReceiveCallback, Texture};
 use nexus::texture_receive;
 use std::collections::HashMap;
+use std::fs;
 use std::thread;
+use std::time::Duration;
 
 pub const RECEIVE_TEXTURE: RawTextureReceiveCallback = texture_receive!(receive_texture);
 pub const TEXTURE_PREFIX: &str = "ITEM_DETAIL_POPUPS_URL_";
 
+// thumbnail widths requested from the wiki, so nearby sizes share one texture
+const THUMBNAIL_SIZE_BUCKETS: [u32; 13] =
+    [16, 24, 32, 48, 64, 96, 128, 192, 256, 384, 512, 768, 1024];
+
+const FAILED_TEXTURE_RETRY_COOLDOWN: Duration = Duration::from_secs(30);
+
 pub type TextureCache = HashMap<String, CachedData<Texture>>;
 
 impl<'a> StoreInCache<'a, TextureCache, CachedData<Texture>, String> for TextureCache {
@@ -25,7 +37,10 @@ impl<'a> StoreInCache<'a, TextureCache, CachedData<Texture>, String> for Texture
         let mut should_start_caching = false;
         let result = match self.get(&texture_id_with_prefix) {
             Some(texture_cached_data) => {
-                let cache_expiration_duration = read_config().max_texture_expiration_duration;
+                let cache_expiration_duration = match texture_cached_data.caching_status {
+                    CachingStatus::Failed => FAILED_TEXTURE_RETRY_COOLDOWN,
+                    _ => read_config().max_texture_expiration_duration,
+                };
                 let mut result = texture_cached_data.clone();
                 if is_cache_expired(cache_expiration_duration, texture_cached_data.date)
                     && !matches!(&texture_cached_data.caching_status, CachingStatus::Caching)
@@ -55,6 +70,7 @@ impl<'a> StoreInCache<'a, TextureCache, CachedData<Texture>, String> for Texture
 pub fn fetch_texture_thread(texture_id: String) {
     lock_threads().push(thread::spawn(move || {
         debug!("[fetch_texture_thread] started for {}", texture_id);
+        let texture_id_with_prefix = format!("{}{}", TEXTURE_PREFIX, texture_id);
         let mut path = textures_dir();
         path.push(identifier_to_filename(&texture_id));
         if !path.exists() {
@@ -62,27 +78,83 @@ pub fn fetch_texture_thread(texture_id: String) {
                 "[fetch_texture_thread] File does not exist, downloading: {}",
                 path.display()
             );
-            if let Err(e) = download_wiki_image(&texture_id) {
-                error!("[fetch_texture_thread] failed to download image: {}", e);
+            if let Err(e) = download_texture(&texture_id) {
+                e.log();
+                error!("[fetch_texture_thread] failed to download image: {texture_id}");
+                mark_texture_failed(texture_id_with_prefix);
                 return;
             }
         }
-        let texture_id_with_prefix = format!("{}{}", TEXTURE_PREFIX, texture_id);
         load_texture_from_file(texture_id_with_prefix, path, Some(RECEIVE_TEXTURE));
     }));
 }
 
+// The wiki refuses to upscale, so a thumb wider than its original falls back to the original.
+fn download_texture(texture_id: &String) -> Result<(), ApiError> {
+    let original = full_resolution_href(texture_id);
+    match download_wiki_image(texture_id) {
+        Err(_) if original != *texture_id => {
+            debug!("[download_texture] thumb unavailable, falling back to {original}");
+            download_wiki_image_as(&original, texture_id)
+        }
+        result => result,
+    }
+}
+
+fn thumbnail_size_bucket(pixels: f32) -> u32 {
+    THUMBNAIL_SIZE_BUCKETS
+        .iter()
+        .copied()
+        .find(|bucket| *bucket as f32 >= pixels)
+        .unwrap_or(THUMBNAIL_SIZE_BUCKETS[THUMBNAIL_SIZE_BUCKETS.len() - 1])
+}
+
+// Thumb of the href for the given on-screen width; other images are kept as they are.
+pub fn sized_texture_href(href: &str, pixels: f32) -> String {
+    if !href.starts_with("/images/") {
+        return href.to_string();
+    }
+    sized_thumbnail_href(href, thumbnail_size_bucket(pixels))
+}
+
+// Thumbs already narrower than max_pixels are kept, so small images are never re-requested larger.
+pub fn capped_texture_href(href: &str, max_pixels: f32) -> String {
+    let bucket = thumbnail_size_bucket(max_pixels);
+    match thumbnail_width(href) {
+        Some(width) if width <= bucket => href.to_string(),
+        _ => sized_texture_href(href, max_pixels),
+    }
+}
+
 pub fn identifier_to_filename(identifier: &str) -> String {
-    identifier.replace("/", "_").replace("\\", "_")
+    identifier
+        .replace("/", "_")
+        .replace("\\", "_")
+        .replace(":", "_")
 }
 
 pub fn receive_texture(id: &str, texture: Option<&Texture>) {
-    if texture.is_none() {
+    let Some(texture) = texture else {
+        error!("[receive_texture] failed to load texture {id}");
+        // the file is removed so the next attempt downloads it again
+        let mut path = textures_dir();
+        path.push(identifier_to_filename(
+            id.strip_prefix(TEXTURE_PREFIX).unwrap_or(id),
+        ));
+        let _ = fs::remove_file(path);
+        mark_texture_failed(id.to_string());
         return;
-    }
+    };
     write_context().cache.textures.insert(
         id.to_string(),
-        CachedData::new_with_value(Local::now(), texture.unwrap().clone())
+        CachedData::new_with_value(Local::now(), texture.clone())
             .with_caching_status(CachingStatus::Cached),
     );
 }
+
+fn mark_texture_failed(texture_id_with_prefix: String) {
+    write_context().cache.textures.insert(
+        texture_id_with_prefix,
+        CachedData::new(Local::now()).with_caching_status(CachingStatus::Failed),
+    );
+}
@@ -7,6 +7,7 @@ use chrono::{DateTime, Local};
 use log::trace;
 use std::sync::{OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::thread;
+use std::time::Duration;
 
 pub(crate) static CONTEXT: OnceLock<RwLock<Context>> = OnceLock::new();
 
@@ -73,5 +74,21 @@ pub fn read_context() -> RwLockReadGuard<'static, Context> {
 
 pub fn save_cache() {
     read_context().cache.item_names.save();
+    read_context().cache.professions.save();
+    read_context().cache.price_history.save();
     read_context().cache.popup_data_map.save();
+    read_context().cache.notes.save();
+}
+
+// Notes are user-written, so they are saved shortly after typing stops instead of periodically.
+pub fn save_notes_if_changed(delay: Duration) {
+    let notes = {
+        let context = read_context();
+        if !context.cache.notes.has_unsaved_changes(delay) {
+            return;
+        }
+        context.cache.notes.clone()
+    };
+    notes.save();
+    write_context().cache.notes.mark_saved(&notes);
 }
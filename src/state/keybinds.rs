@@ -1,4 +1,5 @@
-use crate::api::gw2_wiki::prepare_item_popup_with_quantity;
+use crate::api::gw2_wiki::{prepare_chat_link_popup, prepare_item_popup_with_quantity};
+use crate::service::chat_code::{decode_chat_code, extract_chat_code};
 use crate::service::item_tag_parser::extract_item_details;
 use crate::service::keyboard::{trigger_key_combination, KeyCombination};
 use crate::state::context::{read_context, write_context};
@@ -65,6 +66,30 @@ pub fn register_show_popup_keybind() {
     .revert_on_unload();
 }
 
+pub fn register_toggle_watchlist_keybind() {
+    let keybind_handler = keybind_handler!(|_id, is_release| {
+        debug!("[register_toggle_watchlist_keybind]");
+        if is_release {
+            let mut context = write_context();
+            context.ui.watchlist_opened = !context.ui.watchlist_opened;
+        }
+    });
+    register_keybind_with_string("Toggle price watchlist", keybind_handler, "CTRL+SHIFT+W")
+        .revert_on_unload();
+}
+
+pub fn register_toggle_bookmarks_keybind() {
+    let keybind_handler = keybind_handler!(|_id, is_release| {
+        debug!("[register_toggle_bookmarks_keybind]");
+        if is_release {
+            let mut context = write_context();
+            context.ui.bookmarks_opened = !context.ui.bookmarks_opened;
+        }
+    });
+    register_keybind_with_string("Toggle bookmarks", keybind_handler, "CTRL+SHIFT+B")
+        .revert_on_unload();
+}
+
 pub fn register_open_search_keybind() {
     let keybind_handler = keybind_handler!(|_id, is_release| {
         debug!("[register_open_search_keybind]");
@@ -81,6 +106,16 @@ fn process_clipboard_text() {
         Ok(clipboard_text) => {
             debug!("[process_clipboard_text] text = {}", clipboard_text);
             write_context().last_clipboard_text = Some(clipboard_text.clone());
+            if let Some(chat_code) = extract_chat_code(&clipboard_text) {
+                match decode_chat_code(&chat_code) {
+                    Ok(chat_link) => {
+                        write_context().ui.hovered_popup =
+                            prepare_chat_link_popup(&chat_link, &chat_code);
+                    }
+                    Err(e) => error!("{}", e),
+                }
+                return;
+            }
             let item_details = extract_item_details(&clipboard_text);
             match item_details {
                 Ok(item_details) => {
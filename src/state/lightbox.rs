@@ -0,0 +1,69 @@
+use crate::state::popup::token::Token;
+
+pub const MIN_ZOOM: f32 = 0.1;
+pub const MAX_ZOOM: f32 = 10.0;
+
+#[derive(Clone, Debug)]
+pub struct LightboxImage {
+    pub href: String,
+    pub caption: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Lightbox {
+    pub images: Vec<LightboxImage>,
+    pub index: usize,
+    pub zoom: f32,
+    pub pan: [f32; 2],
+}
+
+impl Lightbox {
+    // The images tab lists every image followed by its caption, if it has one.
+    pub fn from_tokens(tokens: &[Token], href: &str) -> Option<Self> {
+        let mut images: Vec<LightboxImage> = vec![];
+        for token in tokens {
+            match token {
+                Token::Image(image_href, _) => images.push(LightboxImage {
+                    href: image_href.clone(),
+                    caption: None,
+                }),
+                Token::Text(text, _) => {
+                    if let Some(image) = images.last_mut().filter(|image| image.caption.is_none()) {
+                        image.caption = Some(text.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+        let index = images.iter().position(|image| image.href == href)?;
+        Some(Self {
+            images,
+            index,
+            zoom: 1.0,
+            pan: [0.0, 0.0],
+        })
+    }
+
+    pub fn current(&self) -> &LightboxImage {
+        &self.images[self.index]
+    }
+
+    pub fn previous(&mut self) {
+        self.index = (self.index + self.images.len() - 1) % self.images.len();
+        self.reset_view();
+    }
+
+    pub fn next(&mut self) {
+        self.index = (self.index + 1) % self.images.len();
+        self.reset_view();
+    }
+
+    pub fn zoom_by(&mut self, factor: f32) {
+        self.zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+
+    pub fn reset_view(&mut self) {
+        self.zoom = 1.0;
+        self.pan = [0.0, 0.0];
+    }
+}
@@ -3,6 +3,7 @@ pub mod clipboard;
 pub mod context;
 pub mod font;
 pub mod keybinds;
+pub mod lightbox;
 pub mod links;
 pub mod mumble;
 pub mod popup;
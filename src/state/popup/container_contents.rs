@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum ContentsKind {
+    Contents,
+    Salvage,
+}
+
+impl Display for ContentsKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentsKind::Contents => write!(f, "Contents"),
+            ContentsKind::Salvage => write!(f, "Salvage results"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContentsOutcome {
+    pub name: String,
+    pub href: String,
+    pub min_quantity: f32,
+    pub max_quantity: f32,
+    // None when the wiki does not give a chance
+    pub probability: Option<f32>,
+}
+
+impl ContentsOutcome {
+    // Outcomes without a chance can't be weighted, so they have no expected quantity.
+    pub fn expected_quantity(&self) -> Option<f32> {
+        self.probability
+            .map(|probability| (self.min_quantity + self.max_quantity) / 2.0 * probability)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContainerContents {
+    pub kind: ContentsKind,
+    pub outcomes: Vec<ContentsOutcome>,
+}
+
+impl ContainerContents {
+    pub fn has_probabilities(&self) -> bool {
+        self.outcomes
+            .iter()
+            .any(|outcome| outcome.probability.is_some())
+    }
+}
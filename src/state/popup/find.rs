@@ -0,0 +1,127 @@
+use crate::configuration::popup::rendering_params::RenderingParams;
+use crate::configuration::popup::tab_rules::ArticleType;
+use crate::state::popup::popup_data::PopupData;
+use crate::state::popup::table_params::CellSpan;
+use crate::state::popup::table_state::TableState;
+use crate::state::popup::token::Token;
+use std::collections::HashMap;
+
+pub const GENERAL_TAB: &str = "General";
+
+#[derive(Clone, Debug, Default)]
+pub struct FindState {
+    pub query: String,
+    pub current_match: usize,
+    pub focus_input: bool,
+    pub scroll_to_current: bool,
+}
+
+// Matches are counted in rendering order, so their position identifies them across tabs.
+pub struct TextHighlight {
+    query: String,
+    current_match: usize,
+    next_match: usize,
+    pub scroll_to_current: bool,
+}
+
+impl TextHighlight {
+    pub fn new(find_state: &FindState, first_match: usize) -> Option<Self> {
+        if find_state.query.trim().is_empty() {
+            return None;
+        }
+        Some(Self {
+            query: find_state.query.to_ascii_lowercase(),
+            current_match: find_state.current_match,
+            next_match: first_match,
+            scroll_to_current: find_state.scroll_to_current,
+        })
+    }
+
+    // Byte ranges of the matches in text, with whether each is the current match.
+    pub fn matches(&mut self, text: &str) -> Vec<(usize, usize, bool)> {
+        let ranges = match_ranges(text, &self.query);
+        let result = ranges
+            .iter()
+            .enumerate()
+            .map(|(index, (start, end))| {
+                (*start, *end, self.next_match + index == self.current_match)
+            })
+            .collect();
+        self.next_match += ranges.len();
+        result
+    }
+}
+
+pub fn match_ranges(text: &str, query: &str) -> Vec<(usize, usize)> {
+    let query = query.to_ascii_lowercase();
+    if query.is_empty() {
+        return vec![];
+    }
+    text.to_ascii_lowercase()
+        .match_indices(&query)
+        .map(|(start, matched)| (start, start + matched.len()))
+        .collect()
+}
+
+// Tab of every match, in the order the tabs and their tokens are rendered.
+pub fn find_matches(
+    data: &PopupData,
+    query: &str,
+    rendering_params: &RenderingParams,
+    table_states: &HashMap<String, TableState>,
+) -> Vec<String> {
+    let mut result = vec![];
+    let general_tab = rendering_params
+        .show_general_tab
+        .then_some((GENERAL_TAB, &data.description));
+    let article_type = ArticleType::detect(data);
+    let tabs = general_tab.into_iter().chain(
+        rendering_params
+            .tab_rules
+            .ordered_sections(&data.sections, &article_type)
+            .into_iter()
+            .map(|(section_name, tokens)| (section_name.as_str(), tokens)),
+    );
+    for (tab_name, tokens) in tabs {
+        let count = count_matches(tokens, query, Some(table_states));
+        result.extend((0..count).map(|_| tab_name.to_string()));
+    }
+    result
+}
+
+// Tables count only what they render: visible cells, in filtered and sorted row order.
+fn count_matches(
+    tokens: &[Token],
+    query: &str,
+    table_states: Option<&HashMap<String, TableState>>,
+) -> usize {
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Text(text, _) => match_ranges(text, query).len(),
+            Token::Tag(tag_params) => match_ranges(&tag_params.text, query).len(),
+            Token::Table(table_params) => {
+                let Some(table_states) = table_states else {
+                    return 0;
+                };
+                let default_state = TableState::default();
+                let table_state = table_states
+                    .get(&table_params.uuid)
+                    .unwrap_or(&default_state);
+                let visible_columns = table_state.visible_columns(table_params);
+                table_state
+                    .visible_rows(table_params)
+                    .into_iter()
+                    .flat_map(|row| {
+                        visible_columns
+                            .iter()
+                            .filter_map(move |column| table_params.rows[row].cells.get(*column))
+                    })
+                    .filter(|cell| matches!(cell.span, CellSpan::Origin))
+                    .map(|cell| count_matches(&cell.tokens, query, None))
+                    .sum()
+            }
+            _ => 0,
+        })
+        .sum()
+}
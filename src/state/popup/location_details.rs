@@ -0,0 +1,14 @@
+use crate::state::popup::tag_params::TagParams;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+pub type NearbyCategory = String;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct LocationDetails {
+    pub chat_code: Option<String>,
+    pub map_name: Option<String>,
+    pub region: Option<String>,
+    pub area: Option<String>,
+    pub nearby: IndexMap<NearbyCategory, Vec<TagParams>>,
+}
@@ -1,12 +1,18 @@
 use crate::state::context::read_context;
+use navigation::NavigationEntry;
 use popup_data::PopupData;
 use popup_state::PopupState;
 
+pub mod container_contents;
 pub mod dimensions;
+pub mod find;
+pub mod location_details;
+pub mod navigation;
 pub mod popup_data;
 pub mod popup_state;
 pub mod style;
 pub mod table_params;
+pub mod table_state;
 pub mod tag_params;
 pub mod token;
 
@@ -17,6 +23,14 @@ pub struct Popup {
 }
 
 impl Popup {
+    pub fn navigation_entry(&self) -> NavigationEntry {
+        NavigationEntry {
+            href: self.data.href.clone(),
+            title: self.data.title.clone(),
+            item_ids: self.data.item_ids.clone(),
+        }
+    }
+
     pub fn new(data: PopupData) -> Self {
         Self {
             state: PopupState::new(),
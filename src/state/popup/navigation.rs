@@ -0,0 +1,54 @@
+const MAX_HISTORY_LENGTH: usize = 50;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavigationEntry {
+    pub href: String,
+    pub title: String,
+    // kept so pages opened by id, e.g. from chat codes, don't fall back to a lookup by title
+    pub item_ids: Option<Vec<u32>>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Navigation {
+    back: Vec<NavigationEntry>,
+    forward: Vec<NavigationEntry>,
+}
+
+impl Navigation {
+    pub fn is_empty(&self) -> bool {
+        self.back.is_empty() && self.forward.is_empty()
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    // History of a popup opened by following a link from the current page.
+    pub fn followed_from(&self, current: NavigationEntry) -> Self {
+        let mut back = self.back.clone();
+        back.push(current);
+        if back.len() > MAX_HISTORY_LENGTH {
+            back.remove(0);
+        }
+        Self {
+            back,
+            forward: vec![],
+        }
+    }
+
+    pub fn go_back(&mut self, current: NavigationEntry) -> Option<NavigationEntry> {
+        let target = self.back.pop()?;
+        self.forward.push(current);
+        Some(target)
+    }
+
+    pub fn go_forward(&mut self, current: NavigationEntry) -> Option<NavigationEntry> {
+        let target = self.forward.pop()?;
+        self.back.push(current);
+        Some(target)
+    }
+}
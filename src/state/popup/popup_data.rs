@@ -3,6 +3,8 @@ use std::collections::BTreeMap;
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 
+use super::container_contents::ContainerContents;
+use super::location_details::LocationDetails;
 use super::token::Token;
 use crate::configuration::config_dir;
 
@@ -30,6 +32,12 @@ pub struct PopupData {
     pub cached_date: DateTime<Local>,
     pub href: String,
     pub redirection_href: Option<String>,
+    #[serde(default)]
+    pub infobox: IndexMap<String, String>,
+    #[serde(default)]
+    pub location: Option<LocationDetails>,
+    #[serde(default)]
+    pub contents: Option<ContainerContents>,
 }
 
 impl PopupData {
@@ -38,6 +46,7 @@ impl PopupData {
             || self.sections.iter().any(|(_, tokens)| !tokens.is_empty())
             || !self.images.is_empty()
             || self.item_ids.is_some()
+            || self.location.is_some()
     }
 }
 
@@ -54,6 +63,9 @@ impl Default for PopupData {
             cached_date: Local::now(),
             href: "".to_string(),
             redirection_href: None,
+            infobox: IndexMap::new(),
+            location: None,
+            contents: None,
         }
     }
 }
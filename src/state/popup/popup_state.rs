@@ -2,6 +2,11 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 use once_cell::sync::Lazy;
 
+use crate::state::popup::find::FindState;
+use crate::state::popup::navigation::Navigation;
+use crate::state::popup::table_state::TableState;
+use std::collections::HashMap;
+
 static POPUP_ID_COUNTER: Lazy<AtomicU64> = Lazy::new(|| {
     AtomicU64::new(
         std::time::SystemTime::now()
@@ -21,6 +26,13 @@ pub struct PopupState {
     pub pos: Option<[f32; 2]>,
     pub width: Option<f32>,
     pub item_quantity: usize,
+    pub custom_sell_price: Option<u32>,
+    pub navigation: Navigation,
+    pub selected_tab: Option<String>,
+    pub restore_selected_tab: bool,
+    pub clicked_tab: Option<String>,
+    pub find: Option<FindState>,
+    pub tables: HashMap<String, TableState>,
 }
 
 impl Default for PopupState {
@@ -41,6 +53,13 @@ impl PopupState {
             width: None,
             collapsed: false,
             item_quantity: 1,
+            custom_sell_price: None,
+            navigation: Navigation::default(),
+            selected_tab: None,
+            restore_selected_tab: false,
+            clicked_tab: None,
+            find: None,
+            tables: HashMap::new(),
         }
     }
 
@@ -42,9 +42,20 @@ impl TableRow {
     }
 }
 
+// Cells covered by a rowspan or colspan hold a copy of the spanning cell.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub enum CellSpan {
+    #[default]
+    Origin,
+    Row,
+    Column,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TableCell {
     pub tokens: Vec<Token>,
+    #[serde(default)]
+    pub span: CellSpan,
 }
 
 impl Default for TableCell {
@@ -55,6 +66,9 @@ impl Default for TableCell {
 
 impl TableCell {
     pub fn new() -> Self {
-        Self { tokens: vec![] }
+        Self {
+            tokens: vec![],
+            span: CellSpan::Origin,
+        }
     }
 }
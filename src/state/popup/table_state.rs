@@ -0,0 +1,166 @@
+use crate::state::popup::table_params::{TableCell, TableParams};
+use crate::state::popup::token::Token;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+const COIN_VALUES: [(&str, f64); 3] = [
+    ("gold_coin", 10000.0),
+    ("silver_coin", 100.0),
+    ("copper_coin", 1.0),
+];
+
+#[derive(Clone, Debug, Default)]
+pub struct TableState {
+    pub filter: String,
+    pub sort_column: Option<usize>,
+    pub sort_ascending: bool,
+    pub hidden_columns: HashSet<usize>,
+}
+
+impl TableState {
+    pub fn toggle_sort(&mut self, column: usize) {
+        match self.sort_column {
+            Some(sort_column) if sort_column == column && self.sort_ascending => {
+                self.sort_ascending = false
+            }
+            Some(sort_column) if sort_column == column => self.sort_column = None,
+            _ => {
+                self.sort_column = Some(column);
+                self.sort_ascending = true;
+            }
+        }
+    }
+
+    pub fn visible_columns(&self, table_params: &TableParams) -> Vec<usize> {
+        (0..table_params.headers.len())
+            .filter(|column| !self.hidden_columns.contains(column))
+            .collect()
+    }
+
+    // Indices of the rows to render, filtered on their visible cells and in sort order.
+    pub fn visible_rows(&self, table_params: &TableParams) -> Vec<usize> {
+        let filter = self.filter.trim().to_lowercase();
+        let visible_columns = self.visible_columns(table_params);
+        let mut rows: Vec<usize> = table_params
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                filter.is_empty()
+                    || visible_columns
+                        .iter()
+                        .filter_map(|column| row.cells.get(*column))
+                        .any(|cell| cell_text(cell).to_lowercase().contains(&filter))
+            })
+            .map(|(index, _)| index)
+            .collect();
+        if let Some(column) = self.sort_column {
+            let keys: Vec<SortKey> = table_params
+                .rows
+                .iter()
+                .map(|row| {
+                    row.cells
+                        .get(column)
+                        .map(SortKey::from_cell)
+                        .unwrap_or(SortKey::Empty)
+                })
+                .collect();
+            rows.sort_by(|a, b| {
+                let ordering = keys[*a].compare(&keys[*b]);
+                if self.sort_ascending {
+                    ordering
+                } else {
+                    ordering.reverse()
+                }
+            });
+        }
+        rows
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum SortKey {
+    Number(f64),
+    Text(String),
+    Empty,
+}
+
+impl SortKey {
+    fn from_cell(cell: &TableCell) -> Self {
+        if let Some(copper) = coin_value(&cell.tokens) {
+            return SortKey::Number(copper);
+        }
+        let text = cell_text(cell);
+        if text.trim().is_empty() {
+            return SortKey::Empty;
+        }
+        match parse_number(&text) {
+            Some(number) => SortKey::Number(number),
+            None => SortKey::Text(text.trim().to_lowercase()),
+        }
+    }
+
+    // Numbers come before text, empty cells always last.
+    fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (SortKey::Number(a), SortKey::Number(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            (SortKey::Text(a), SortKey::Text(b)) => a.cmp(b),
+            (SortKey::Number(_), _) | (SortKey::Text(_), SortKey::Empty) => Ordering::Less,
+            (SortKey::Empty, SortKey::Empty) => Ordering::Equal,
+            _ => Ordering::Greater,
+        }
+    }
+}
+
+fn cell_text(cell: &TableCell) -> String {
+    cell.tokens
+        .iter()
+        .filter_map(|token| match token {
+            Token::Text(text, _) => Some(text.as_str()),
+            Token::Tag(tag_params) => Some(tag_params.text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+// Prices render as amounts followed by coin icons, e.g. "1 [gold] 20 [silver] 5 [copper]".
+fn coin_value(tokens: &[Token]) -> Option<f64> {
+    let mut total = None;
+    let mut amount = None;
+    for token in tokens {
+        match token {
+            Token::Text(text, _) => amount = parse_number(text).or(amount),
+            Token::Image(href, _) => {
+                let href = href.to_lowercase();
+                let Some((_, value)) = COIN_VALUES.iter().find(|(name, _)| href.contains(name))
+                else {
+                    continue;
+                };
+                total = Some(total.unwrap_or(0.0) + amount.take().unwrap_or(0.0) * value);
+            }
+            _ => {}
+        }
+    }
+    total
+}
+
+// Leading number of the text, ignoring thousands separators: "1,250", "25%", "-4.5 sec".
+fn parse_number(text: &str) -> Option<f64> {
+    let text = text.replace(',', "");
+    let start = text.find(|c: char| c.is_alphanumeric())?;
+    if !text[start..].starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let start = match text[..start].chars().last() {
+        Some('-') => start - 1,
+        _ => start,
+    };
+    let number: String = text[start..]
+        .chars()
+        .enumerate()
+        .take_while(|(index, c)| c.is_ascii_digit() || *c == '.' || (*index == 0 && *c == '-'))
+        .map(|(_, c)| c)
+        .collect();
+    number.parse().ok()
+}
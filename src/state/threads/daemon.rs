@@ -1,9 +1,10 @@
 use crate::state::cache::{is_cache_expired, Persist, StoreInCache};
 
-use crate::configuration::read_config;
 use crate::configuration::textures_dir;
+use crate::configuration::{read_config, write_config};
 use crate::render::popup_data::price::{COPPER_COIN_HREF, GOLD_COIN_HREF, SILVER_COIN_HREF};
-use crate::state::context::{read_context, save_cache, write_context};
+use crate::service::layout::capture_layout;
+use crate::state::context::{read_context, save_cache, save_notes_if_changed, write_context};
 use crate::state::threads::cache::clean_expired_cache;
 use crate::state::threads::font::{load_fonts, preselect_fonts};
 use crate::threads::lock_threads;
@@ -17,6 +18,7 @@ const DAEMON_THREAD_SLEEP_DURATION_MS: u64 = 50;
 const GC_INTERVAL_SEC: u64 = 120;
 const CONFIG_SAVE_INTERVAL_SEC: u64 = 5;
 const CACHE_SAVE_INTERVAL_SEC: u64 = 60;
+const NOTES_SAVE_DELAY: Duration = Duration::from_secs(2);
 
 pub fn daemon_thread() {
     lock_threads().push(thread::spawn(|| loop {
@@ -29,6 +31,8 @@ pub fn daemon_thread() {
         if now
             > read_context().last_config_save_date + Duration::from_secs(CONFIG_SAVE_INTERVAL_SEC)
         {
+            let pinned_popups = capture_layout(&read_context().ui.pinned_popups);
+            write_config().layouts.pinned_popups = pinned_popups;
             read_config().save();
             write_context().last_config_save_date = now;
         }
@@ -39,6 +43,8 @@ pub fn daemon_thread() {
             write_context().last_cache_save_date = now;
         }
 
+        save_notes_if_changed(NOTES_SAVE_DELAY);
+
         if now > read_context().last_gc_date + Duration::from_secs(GC_INTERVAL_SEC) {
             clean_expired_cache();
             clean_expired_textures();
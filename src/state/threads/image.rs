@@ -0,0 +1,36 @@
+use crate::api::gw2_wiki::download_wiki_image;
+use crate::configuration::{saved_images_dir, textures_dir};
+use crate::state::cache::texture::identifier_to_filename;
+use crate::threads::lock_threads;
+use log::{debug, error};
+use nexus::alert::send_alert;
+use std::fs;
+use std::path::Path;
+use std::thread;
+
+pub fn save_image_thread(href: String) {
+    lock_threads().push(thread::spawn(move || {
+        debug!("[save_image_thread] started for {href}");
+        let mut source = textures_dir();
+        source.push(identifier_to_filename(&href));
+        if !source.exists() {
+            if let Err(e) = download_wiki_image(&href) {
+                e.log();
+                send_alert("Couldn't download the image.");
+                return;
+            }
+        }
+        let file_name = href.rsplit('/').next().unwrap_or(&href).to_string();
+        let mut target = saved_images_dir();
+        let _ = fs::create_dir_all(&target);
+        // downloaded images are always normalized to png
+        target.push(Path::new(&identifier_to_filename(&file_name)).with_extension("png"));
+        match fs::copy(&source, &target) {
+            Ok(_) => send_alert(format!("Image saved to \"{}\".", target.display())),
+            Err(e) => {
+                error!("[save_image_thread] failed to save image: {}", e);
+                send_alert("Couldn't save the image.");
+            }
+        }
+    }));
+}
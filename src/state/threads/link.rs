@@ -16,3 +16,45 @@ pub fn open_link_thread(href: String, title: String) {
         write_context().ui.loading_progress = None;
     }));
 }
+
+pub fn open_pinned_link_thread(href: String, title: String, pos: [f32; 2]) {
+    debug!(
+        "[open_pinned_link_thread] Opening link with href: {} and title: {}",
+        href, title
+    );
+    lock_threads().push(thread::spawn(move || {
+        write_context().ui.loading_progress = Some(1);
+        let mut popup = prepare_href_popup(&href, title);
+        popup.state.opened = true;
+        popup.state.pinned = true;
+        popup.state.pos = Some(pos);
+        let mut context = write_context();
+        context.ui.pinned_popups.push(popup);
+        context.ui.loading_progress = None;
+    }));
+}
+
+// Item ids are taken from the title unless given.
+pub fn navigate_popup_thread(
+    popup_id: u64,
+    href: String,
+    title: String,
+    item_ids: Option<Vec<u32>>,
+) {
+    debug!(
+        "[navigate_popup_thread] Navigating popup {} to href: {}",
+        popup_id, href
+    );
+    lock_threads().push(thread::spawn(move || {
+        write_context().ui.loading_progress = Some(1);
+        let mut popup = prepare_href_popup(&href, title);
+        if item_ids.is_some() {
+            popup.data.item_ids = item_ids;
+        }
+        let mut context = write_context();
+        if let Some(navigated_popup) = context.ui.popup_mut(popup_id) {
+            navigated_popup.data = popup.data;
+        }
+        context.ui.loading_progress = None;
+    }));
+}
@@ -1,5 +1,7 @@
 pub mod cache;
 pub mod daemon;
 pub mod font;
+pub mod image;
 pub mod link;
 pub mod popup;
+pub mod watchlist;
@@ -0,0 +1,105 @@
+use crate::api::gw2::price::price_api_response::PriceApiResponse;
+use crate::api::gw2::price::MAX_IDS_PER_REQUEST;
+use crate::api::provider::fetch_prices;
+use crate::configuration::read_config;
+use crate::configuration::watchlist::WatchlistEntry;
+use crate::state::cache::price::{cache_price, Price};
+use crate::state::context::read_context;
+use crate::threads::lock_threads;
+use crate::utils::coins::format_coins;
+use chrono::{DateTime, Local};
+use log::debug;
+use nexus::alert::send_alert;
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+
+const WATCHLIST_THREAD_SLEEP_DURATION_MS: u64 = 200;
+
+pub fn watchlist_thread() {
+    lock_threads().push(thread::spawn(|| {
+        let mut last_prices: HashMap<u32, Price> = HashMap::new();
+        let mut last_poll_date: Option<DateTime<Local>> = None;
+        loop {
+            if !read_context().run_background_thread {
+                break;
+            }
+            let poll_interval = read_config().watchlist.poll_interval;
+            let should_poll = match last_poll_date {
+                Some(date) => Local::now() > date + poll_interval,
+                None => true,
+            };
+            if should_poll {
+                poll_watchlist(&mut last_prices);
+                last_poll_date = Some(Local::now());
+            }
+            thread::sleep(Duration::from_millis(WATCHLIST_THREAD_SLEEP_DURATION_MS));
+        }
+    }));
+}
+
+fn poll_watchlist(last_prices: &mut HashMap<u32, Price>) {
+    let entries = read_config().watchlist.entries.clone();
+    if entries.is_empty() {
+        return;
+    }
+    debug!("[poll_watchlist] started for {} items", entries.len());
+    let item_ids: Vec<u32> = entries.iter().map(|entry| entry.item_id).collect();
+    for chunk in item_ids.chunks(MAX_IDS_PER_REQUEST) {
+        match fetch_prices(chunk) {
+            Ok(prices) => {
+                for price_data in prices {
+                    if let Some(entry) = entries.iter().find(|e| e.item_id == price_data.id) {
+                        check_thresholds(entry, &price_data, last_prices);
+                    }
+                    cache_price(&price_data);
+                }
+            }
+            Err(api_error) => api_error.log(),
+        }
+    }
+}
+
+fn check_thresholds(
+    entry: &WatchlistEntry,
+    price_data: &PriceApiResponse,
+    last_prices: &mut HashMap<u32, Price>,
+) {
+    let current = Price {
+        highest_buy: price_data.buys.unit_price,
+        lowest_sell: price_data.sells.unit_price,
+    };
+    if let Some(previous) = last_prices.get(&entry.item_id) {
+        if entry.sell_threshold_crossed(previous.lowest_sell, current.lowest_sell) {
+            send_alert(threshold_alert(
+                &entry.name,
+                "Sell",
+                current.lowest_sell,
+                entry.sell_threshold,
+            ));
+        }
+        if entry.buy_threshold_crossed(previous.highest_buy, current.highest_buy) {
+            send_alert(threshold_alert(
+                &entry.name,
+                "Buy",
+                current.highest_buy,
+                entry.buy_threshold,
+            ));
+        }
+    }
+    last_prices.insert(entry.item_id, current);
+}
+
+fn threshold_alert(name: &str, kind: &str, current: u32, threshold: Option<u32>) -> String {
+    let threshold = threshold.unwrap_or_default();
+    let direction = if current < threshold {
+        "dropped below"
+    } else {
+        "rose above"
+    };
+    format!(
+        "{name}: {kind} price {direction} {} (now {}).",
+        format_coins(threshold),
+        format_coins(current)
+    )
+}
@@ -1,4 +1,6 @@
+use crate::configuration::popup::tab_rules::TabRule;
 use crate::state::font::Font;
+use crate::state::lightbox::Lightbox;
 use crate::state::popup::Popup;
 use crate::state::search::search_result::SearchResult;
 
@@ -15,10 +17,24 @@ pub struct UiContext {
     pub search_opened: bool,
     pub search_position: [f32; 2],
     pub bold_font: Option<Font>,
-    pub tab_to_blacklist_input: String,
+    pub new_tab_rule: TabRule,
+    pub watchlist_opened: bool,
+    pub layout_name_input: String,
+    pub compare_opened: bool,
+    pub compared_popup_ids: Vec<u64>,
+    pub bookmarks_opened: bool,
+    pub bookmark_folder_input: String,
+    pub lightbox: Option<Lightbox>,
 }
 
 impl UiContext {
+    pub fn popup_mut(&mut self, popup_id: u64) -> Option<&mut Popup> {
+        self.pinned_popups
+            .iter_mut()
+            .chain(self.hovered_popup.iter_mut())
+            .find(|popup| popup.state.id == popup_id)
+    }
+
     pub fn close_all_popups(&mut self) {
         self.pinned_popups
             .iter_mut()
@@ -1,4 +1,5 @@
 use crate::state::threads::daemon::{daemon_thread, preloader_thread};
+use crate::state::threads::watchlist::watchlist_thread;
 
 use crate::state::cache::gw2_tp::cache_item_names_thread;
 use crate::state::context::write_context;
@@ -41,4 +42,5 @@ pub fn init_threads() {
     cache_item_names_thread();
     daemon_thread();
     preloader_thread();
+    watchlist_thread();
 }
@@ -0,0 +1,12 @@
+pub fn format_coins(price: u32) -> String {
+    let gold = price / 10000;
+    let silver = (price % 10000) / 100;
+    let copper = price % 100;
+    if gold > 0 {
+        format!("{gold}g {silver:02}s {copper:02}c")
+    } else if silver > 0 {
+        format!("{silver}s {copper:02}c")
+    } else {
+        format!("{copper}c")
+    }
+}
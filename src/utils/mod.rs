@@ -1,2 +1,3 @@
+pub mod coins;
 pub mod serde;
 pub mod time;